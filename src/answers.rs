@@ -0,0 +1,236 @@
+//! Accepted answers, recorded so that a change in a solver's output gets noticed.
+//!
+//! Answers are stored in one file per year, `answers/<year>.toml` under the crate root, with one
+//! table per day:
+//!
+//! ```toml
+//! [day5]
+//! part1 = "VWLCWGSDQ"
+//! part2 = 12767
+//! ```
+//!
+//! Only the subset of TOML needed for that is supported: integers, basic strings and multi-line
+//! basic strings (`"""`), which hold bitmaps like the CRT of day 10.
+
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::{Path, PathBuf}};
+
+use crate::{files::{crate_path, read_optional}, Answer, Part};
+
+/// Recorded answers, keyed by day and part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+/// Outcome of checking a computed answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unrecorded,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Part, &Answer)> {
+        self.answers.iter().map(|((day, part), answer)| (*day, *part, answer))
+    }
+
+    /// Compares a computed answer with the recorded one
+    pub fn verify(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected: expected.clone() },
+            None => Verdict::Unrecorded,
+        }
+    }
+
+    /// Parses the contents of an answers file
+    pub fn parse(toml: &str) -> Result<Answers, String> {
+        Answers::parse_with_day(toml, None)
+    }
+
+    /// Parses answers where keys outside of any table belong to `day`
+    pub fn parse_for_day(day: u8, toml: &str) -> Result<Answers, String> {
+        Answers::parse_with_day(toml, Some(day))
+    }
+
+    fn parse_with_day(toml: &str, mut day: Option<u8>) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut lines = toml.lines().enumerate();
+
+        while let Some((line_idx, line)) = lines.next() {
+            let error = |reason: &str| format!("line {}: {reason}", line_idx + 1);
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // Table header, like '[day5]'
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let table_day = table.strip_prefix("day").and_then(|d| d.parse().ok());
+                day = Some(table_day.ok_or_else(|| error("expected a table like '[day5]'"))?);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| error("expected 'part1 = ...' or 'part2 = ...'"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(error("the only keys are 'part1' and 'part2'")),
+            };
+            let day = day.ok_or_else(|| error("answers must be inside a '[dayN]' table"))?;
+            let value = value.trim();
+
+            let answer = if let Some(first_line) = value.strip_prefix("\"\"\"") {
+                // Multi-line string: the text runs until the closing '"""'
+                let mut text = String::new();
+                let mut current = first_line.to_owned();
+                if current.is_empty() { // A newline right after the opening '"""' isn't part of the text
+                    current = lines.next().ok_or_else(|| error("unterminated multi-line string"))?.1.to_owned();
+                }
+                loop {
+                    if let Some(last_line) = current.strip_suffix("\"\"\"") {
+                        text.push_str(last_line);
+                        break;
+                    }
+                    text.push_str(&current);
+                    text.push('\n');
+                    current = lines.next().ok_or_else(|| error("unterminated multi-line string"))?.1.to_owned();
+                }
+                Answer::Bitmap(unescape(&text).ok_or_else(|| error("invalid escape sequence"))?)
+            }
+            else if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Answer::Text(unescape(text).ok_or_else(|| error("invalid escape sequence"))?)
+            }
+            else {
+                Answer::Integer(value.parse().map_err(|_| error("expected an integer or a string"))?)
+            };
+
+            if answers.answers.insert((day, part), answer).is_some() {
+                return Err(error(&format!("day {day} part {part} is recorded twice")));
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Writes the answers back in the format read by [`Answers::parse`]
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut current_day = None;
+
+        for (day, part, answer) in self.iter() {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[day{day}]").unwrap();
+                current_day = Some(day);
+            }
+
+            match answer {
+                Answer::Integer(value) => writeln!(toml, "part{part} = {value}").unwrap(),
+                Answer::Text(text) => writeln!(toml, "part{part} = \"{}\"", escape(text).replace('\n', "\\n")).unwrap(),
+                Answer::Bitmap(text) => writeln!(toml, "part{part} = \"\"\"\n{}\"\"\"", escape(text)).unwrap(),
+            }
+        }
+        toml
+    }
+
+    /// Loads an answers file; a missing file means that nothing was recorded yet
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match read_optional(path)? {
+            Some(toml) => Answers::parse(&toml).map_err(|err| format!("{}: {err}", path.display())),
+            None => Ok(Answers::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?; // The first answers of a year create its file
+        }
+        fs::write(path, self.to_toml())
+    }
+}
+
+// Path of the answers file of a year, resolved from the crate root
+pub fn default_path(year: u16) -> PathBuf {
+    crate_path("answers")
+        .join(format!("{year}.toml"))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Undoes escape(), plus the '\n' and '\t' escapes; returns None for unknown escapes
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        unescaped.push(match chars.next()? {
+            '\\' => '\\',
+            '"' => '"',
+            'n' => '\n',
+            't' => '\t',
+            _ => return None,
+        });
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::InputSource, registry, runner};
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, Answer::Integer(24000));
+        answers.insert(5, Part::Two, Answer::Text("MC\"D\\".to_owned()));
+        answers.insert(10, Part::Two, Answer::Bitmap("##..\n..##".to_owned()));
+        answers.insert(12, Part::One, Answer::Text("a\nb".to_owned()));
+
+        let toml = answers.to_toml();
+        assert_eq!(toml, "[day1]\npart1 = 24000\n\n[day5]\npart2 = \"MC\\\"D\\\\\"\n\n[day10]\npart2 = \"\"\"\n##..\n..##\"\"\"\n\n[day12]\npart1 = \"a\\nb\"\n");
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Answers::parse("part1 = 3").unwrap_err(), "line 1: answers must be inside a '[dayN]' table");
+        assert_eq!(Answers::parse("[day1]\npart1 = 3\npart1 = 4").unwrap_err(), "line 3: day 1 part 1 is recorded twice");
+        assert_eq!(Answers::parse("[day10]\npart2 = \"\"\"\n##").unwrap_err(), "line 2: unterminated multi-line string");
+    }
+
+    // Every registered day whose input is available must still give the recorded answers
+    #[test]
+    fn test_recorded_answers() {
+        for puzzle in registry::all_puzzles() {
+            let answers = Answers::load(&default_path(puzzle.year)).unwrap();
+            let Ok(input) = InputSource::Default.read(puzzle.year, puzzle.day) else {
+                continue;
+            };
+            for variant in puzzle.variants() {
+                for result in runner::solve_variant(puzzle, &input, None, variant).unwrap() {
+                    let verdict = answers.verify(result.day, result.part, &result.answer);
+                    assert!(!matches!(verdict, Verdict::Wrong { .. }), "day {} part {} ({variant}): {verdict:?}, got {}", result.day, result.part, result.answer);
+                }
+            }
+        }
+    }
+}
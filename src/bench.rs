@@ -0,0 +1,276 @@
+//! Timing the stages of a solver over repeated runs.
+//!
+//! Each stage (parsing, part one, part two) is measured separately, and the parts are measured once
+//! per variant of the solver. Results can be saved as CSV and loaded back, so a run can be compared
+//! against an earlier one.
+
+use std::{fmt::Write, fs, hint::black_box, io, path::Path, str::FromStr, time::{Duration, Instant}};
+
+use crate::{registry::{Puzzle, DEFAULT_VARIANT}, AocError, Part};
+
+/// Part of a solver being timed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Solve(Part::One), Stage::Solve(Part::Two)];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Stage, String> {
+        Stage::ALL
+            .into_iter()
+            .find(|stage| stage.name() == name)
+            .ok_or_else(|| format!("unknown stage '{name}'"))
+    }
+}
+
+/// Summary of the wall times of one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Timing {
+    /// Summarizes a non-empty set of samples
+    pub fn from_samples(samples: &mut [Duration]) -> Timing {
+        assert!(!samples.is_empty(), "A timing needs at least one sample");
+        samples.sort();
+
+        Timing {
+            runs: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[percentile_index(samples.len(), 95)],
+        }
+    }
+}
+
+// Index of the nearest-rank percentile in 'len' sorted samples
+pub(crate) fn percentile_index(len: usize, percentile: usize) -> usize {
+    (len * percentile).div_ceil(100).max(1) - 1
+}
+
+/// Timing of one stage of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub variant: String, // Parsing is shared by the variants, so it's always measured as the default one
+    pub timing: Timing,
+}
+
+// Runs 'f' 'runs' times, returning the elapsed time of each run
+fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Times the parsing and both parts of a puzzle (with each of its variants), `runs` times each
+pub fn bench(puzzle: &Puzzle, input: &str, runs: usize) -> Result<Vec<Measurement>, AocError> {
+    let parsed = puzzle.parse(input)?; // Parsing once beforehand, so malformed inputs are reported before timing
+
+    let mut measurements = Vec::new();
+    for stage in Stage::ALL {
+        let variants = match stage {
+            Stage::Parse => vec![DEFAULT_VARIANT],
+            Stage::Solve(_) => puzzle.variants(),
+        };
+        for variant in variants {
+            let mut samples = match stage {
+                Stage::Parse => sample(runs, || puzzle.parse(input)),
+                Stage::Solve(part) => sample(runs, || parsed.solve_variant(variant, part)),
+            };
+            measurements.push(Measurement {
+                year: puzzle.year,
+                day: puzzle.day,
+                stage,
+                variant: variant.to_owned(),
+                timing: Timing::from_samples(&mut samples),
+            });
+        }
+    }
+    Ok(measurements)
+}
+
+// Formats a duration with a unit that keeps it readable
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos} ns"),
+        1_000..1_000_000 => format!("{:.1} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+/* Renders the measurements as an aligned table.
+ *
+ * If a baseline is given, the last column shows how the median of each stage compares to it */
+pub fn render_table(measurements: &[Measurement], baseline: Option<&[Measurement]>) -> String {
+    let mut table = String::new();
+    let variant_width = measurements.iter().map(|m| m.variant.len()).chain([7]).max().unwrap();
+
+    write!(
+        table,
+        "{:>3} | {:<5} | {:<variant_width$} | {:>5} | {:>10} | {:>10} | {:>10}",
+        "Day", "Stage", "Variant", "Runs", "Min", "Median", "p95",
+    ).unwrap();
+    if baseline.is_some() {
+        write!(table, " | {:>11}", "vs baseline").unwrap();
+    }
+    table.push('\n');
+
+    for measurement in measurements {
+        let Measurement { year, day, stage, variant, timing } = measurement;
+        write!(
+            table,
+            "{:>3} | {:<5} | {:<variant_width$} | {:>5} | {:>10} | {:>10} | {:>10}",
+            day,
+            stage.name(),
+            variant,
+            timing.runs,
+            format_duration(timing.min),
+            format_duration(timing.median),
+            format_duration(timing.p95),
+        ).unwrap();
+
+        if let Some(baseline) = baseline {
+            let previous = baseline.iter().find(|m| m.year == *year && m.day == *day && m.stage == *stage && m.variant == *variant);
+            let comparison = match previous {
+                Some(previous) if !previous.timing.median.is_zero() => {
+                    let ratio = timing.median.as_secs_f64() / previous.timing.median.as_secs_f64();
+                    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+                },
+                _ => "-".to_owned(),
+            };
+            write!(table, " | {comparison:>11}").unwrap();
+        }
+        table.push('\n');
+    }
+    table
+}
+
+const CSV_HEADER: &str = "year,day,stage,variant,runs,min_ns,median_ns,p95_ns";
+
+/// Serializes measurements as CSV, with durations in nanoseconds
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for Measurement { year, day, stage, variant, timing } in measurements {
+        writeln!(
+            csv,
+            "{year},{day},{},{variant},{},{},{},{}",
+            stage.name(),
+            timing.runs,
+            timing.min.as_nanos(),
+            timing.median.as_nanos(),
+            timing.p95.as_nanos(),
+        ).unwrap();
+    }
+    csv
+}
+
+/// Reads back measurements written by [`to_csv`]
+pub fn from_csv(csv: &str) -> Result<Vec<Measurement>, String> {
+    let mut lines = csv.lines().enumerate();
+    if lines.next().map(|(_, header)| header) != Some(CSV_HEADER) {
+        return Err(format!("expected the header '{CSV_HEADER}'"));
+    }
+
+    lines
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_idx, line)| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let error = || format!("line {}: invalid measurement '{line}'", line_idx + 1);
+
+            let [year, day, stage, variant, runs, min, median, p95] = fields[..] else {
+                return Err(error());
+            };
+            let nanos = |field: &str| field.parse().map(Duration::from_nanos).map_err(|_| error());
+
+            Ok(Measurement {
+                year: year.parse().map_err(|_| error())?,
+                day: day.parse().map_err(|_| error())?,
+                stage: stage.parse()?,
+                variant: variant.to_owned(),
+                timing: Timing {
+                    runs: runs.parse().map_err(|_| error())?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    p95: nanos(p95)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Saves measurements to a CSV file
+pub fn save(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+    fs::write(path, to_csv(measurements))
+}
+
+/// Loads measurements from a CSV file
+pub fn load(path: &Path) -> Result<Vec<Measurement>, String> {
+    let csv = fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+    from_csv(&csv).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_from_samples() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let timing = Timing::from_samples(&mut samples);
+
+        assert_eq!(timing.runs, 20);
+        assert_eq!(timing.min, Duration::from_millis(1));
+        assert_eq!(timing.median, Duration::from_millis(11));
+        assert_eq!(timing.p95, Duration::from_millis(19));
+
+        let timing = Timing::from_samples(&mut [Duration::from_millis(3)]);
+        assert_eq!((timing.min, timing.median, timing.p95), (Duration::from_millis(3), Duration::from_millis(3), Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let timing = Timing {
+            runs: 10,
+            min: Duration::from_nanos(1_500),
+            median: Duration::from_micros(2),
+            p95: Duration::from_millis(4),
+        };
+        let measurements = vec![
+            Measurement { year: 2022, day: 9, stage: Stage::Parse, variant: "default".to_owned(), timing },
+            Measurement { year: 2021, day: 11, stage: Stage::Solve(Part::Two), variant: "bitmask".to_owned(), timing },
+        ];
+
+        let csv = to_csv(&measurements);
+        assert!(csv.starts_with("year,day,stage,variant,runs,min_ns,median_ns,p95_ns\n2022,9,parse,default,10,1500,2000,4000000\n"));
+        assert_eq!(from_csv(&csv).unwrap(), measurements);
+        assert!(from_csv("day,stage\n").is_err());
+    }
+}
@@ -0,0 +1,462 @@
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process, thread,
+};
+
+use aoc_2022::{
+    answers::{self, Answers, Verdict},
+    bench,
+    client::{Client, ClientConfig, Fetched, Submitted},
+    files,
+    input::{self, InputSource},
+    registry::{self, Puzzle},
+    runner::{self, OutputFormat},
+    scaffold, status,
+    submit::{self, Rejections},
+    visualize::{self, PlayOptions},
+    watch,
+    y2022::day01::{self, Day1, Method, Statistics},
+    Part, Solution,
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc run [--year <YEAR>] (--day <DAY> [--part <PART>] [--input <PATH>] [--variant <NAME>] | --all) [--format <table|json|csv>]
+    aoc bench [--year <YEAR>] (--day <DAY> [--input <PATH>] | --all) [--runs <N>] [--save <CSV>] [--baseline <CSV>]
+    aoc verify [--year <YEAR>] [--day <DAY>] [--record]
+    aoc fetch [--year <YEAR>] --day <DAY>
+    aoc submit [--year <YEAR>] --day <DAY> --part <PART>
+    aoc new [--year <YEAR>] --day <DAY> [--title <TITLE>]
+    aoc watch [--year <YEAR>] --day <DAY>
+    aoc status [--year <YEAR>] [--calendar]
+    aoc calories stats [--input <PATH>] [--bins <N>] [--format <table|json>]
+    aoc calories rebalance [--input <PATH>] [--method <auto|exact|greedy|karmarkar-karp>]
+    aoc animate [--year <YEAR>] --day <DAY> [--part <PART>] [--input <PATH>] [--fps <N>] [--step] [--no-colour] [--export <CAST>]
+
+'--year' selects the event, the most recent one with solved days by default.
+'--input -' reads the input from stdin. By default, inputs are read from 'input/<year>/dayN.txt'.
+'--all' solves every day in parallel and prints a table with one row per day.
+'--variant' solves the day with one of its alternative implementations instead of the default one.
+Benchmarks should be built with '--release'; they time every variant of a day. '--save' stores the
+results so a later run can be compared against them with '--baseline'.
+'verify' checks every day (or only '--day') against 'answers/<year>.toml'; '--record' adds the answers
+that aren't recorded yet, without changing the recorded ones.
+'fetch' downloads an input to 'input/<year>/dayN.txt' unless it's already there. The session token is read
+from AOC_SESSION or from the file AOC_SESSION_FILE (default: '.aoc-session'), and AOC_BASE_URL
+replaces the website URL.
+'submit' solves a part on its input and sends the answer to the website. Accepted answers are added
+to 'answers/<year>.toml' and rejected ones to 'answers/<year>-rejected.csv'; answers known to be
+wrong (or beyond one known to be too high or too low) are never sent.
+'new' creates the solver, example file and test of a new day and registers it (along with its
+year, for the first day of a year).
+'watch' solves a day on its examples and input again whenever 'input/<year>/dayN.txt' or a file of
+'examples/<year>/dayN/' changes, and shows which answers changed. Stop it with Ctrl-C.
+'status' solves every day of a year once and lists, for days 1 to 25, which parts are solved and
+verified, whether the input is there and how long solving took. '--calendar' shows a grid instead.
+'animate' plays the simulation of a day (5, 9 and 10 of 2022) in the terminal, at '--fps' frames per
+second (0.1 to 1000, default: 10). '--step' starts paused; the controls are typed while playing,
+followed by Enter. '--export' writes an asciicast v2 recording instead, for 'asciinema play'.
+Colours are left out with '--no-colour' or when NO_COLOR is set.
+'calories stats' describes the inventory of day 1 of 2022: the number of Elves and items, the mean,
+median, percentiles and standard deviation of their calories, and a histogram of '--bins' bars
+(default: 10).
+'calories rebalance' redistributes the snacks of day 1 of 2022 between the same Elves, without
+splitting any, so that the heaviest load is as light as possible, and compares the loads with the
+inventory's. 'exact' finds the best loads but can take very long on large inventories; 'greedy' and
+'karmarkar-karp' are fast approximations. 'auto' (the default) is exact up to 24 items.";
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+const DEFAULT_FPS: f64 = 10.0;
+const DEFAULT_BINS: usize = 10;
+
+// Options shared by the commands; each command only accepts some of them
+#[derive(Default)]
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<InputSource>,
+    all: bool,
+    runs: Option<usize>,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    record: bool,
+    title: Option<String>,
+    format: Option<OutputFormat>,
+    variant: Option<String>,
+    calendar: bool,
+    fps: Option<f64>,
+    step: bool,
+    no_colour: bool,
+    export: Option<PathBuf>,
+    bins: Option<usize>,
+    method: Option<Method>,
+}
+
+fn parse_options(args: &[String], accepted: &[&str]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if !accepted.contains(&arg.as_str()) {
+            return Err(format!("unexpected argument '{arg}'"));
+        }
+
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--year" => {
+                let year = value()?;
+                options.year = Some(year.parse().map_err(|_| format!("invalid year '{year}'"))?);
+            },
+            "--day" => {
+                let day = value()?;
+                options.day = Some(day.parse().map_err(|_| format!("invalid day '{day}'"))?);
+            },
+            "--part" => options.part = Some(value()?.parse()?),
+            "--input" => options.input = Some(InputSource::from_arg(value()?)),
+            "--all" => options.all = true,
+            "--runs" => {
+                let runs = value()?;
+                options.runs = Some(runs.parse().ok().filter(|&runs| runs > 0).ok_or(format!("invalid number of runs '{runs}'"))?);
+            },
+            "--save" => options.save = Some(PathBuf::from(value()?)),
+            "--baseline" => options.baseline = Some(PathBuf::from(value()?)),
+            "--record" => options.record = true,
+            "--title" => options.title = Some(value()?.to_owned()),
+            "--format" => options.format = Some(value()?.parse()?),
+            "--variant" => options.variant = Some(value()?.to_owned()),
+            "--calendar" => options.calendar = true,
+            "--fps" => {
+                let fps = value()?;
+                let valid = |fps: &f64| (visualize::MIN_FPS..=visualize::MAX_FPS).contains(fps);
+                options.fps = Some(fps.parse().ok().filter(valid).ok_or(format!("invalid frame rate '{fps}' (from {} to {})", visualize::MIN_FPS, visualize::MAX_FPS))?);
+            },
+            "--step" => options.step = true,
+            "--no-colour" => options.no_colour = true,
+            "--export" => options.export = Some(PathBuf::from(value()?)),
+            "--bins" => {
+                let bins = value()?;
+                options.bins = Some(bins.parse().ok().filter(|&bins| bins > 0).ok_or(format!("invalid number of bins '{bins}'"))?);
+            },
+            "--method" => options.method = Some(value()?.parse()?),
+            _ => unreachable!("every accepted argument is handled"),
+        }
+    }
+    Ok(options)
+}
+
+impl Options {
+    fn year(&self) -> u16 {
+        self.year.unwrap_or_else(registry::latest_year)
+    }
+}
+
+// Puzzles of the year selected by '--day' or '--all'
+fn selected_puzzles(options: &Options) -> Result<Vec<&'static Puzzle>, String> {
+    let year = options.year();
+    match (options.day, options.all) {
+        (Some(_), true) => Err("--day and --all can't be used together".to_owned()),
+        (None, true) if options.input.is_some() => Err("--input can only be used with --day".to_owned()),
+        (None, false) => Err("either --day or --all is required".to_owned()),
+        (Some(day), false) => Ok(vec![find_puzzle(year, day)?]),
+        (None, true) if registry::puzzles(year).is_empty() => Err(format!("no day of {year} has a registered solver")),
+        (None, true) => Ok(registry::puzzles(year).iter().collect()),
+    }
+}
+
+fn find_puzzle(year: u16, day: u8) -> Result<&'static Puzzle, String> {
+    registry::find(year, day).ok_or(format!("day {day} of {year} has no registered solver"))
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day", "--part", "--input", "--all", "--format", "--variant"])?;
+    let source = options.input.clone().unwrap_or(InputSource::Default);
+    let puzzles = selected_puzzles(&options)?;
+
+    let variant = options.variant.as_deref().unwrap_or(registry::DEFAULT_VARIANT);
+    if options.variant.is_some() {
+        if options.all {
+            return Err("--variant can only be used with --day".into());
+        }
+        puzzles[0].check_variant(variant)?;
+    }
+
+    if options.all {
+        return run_all(&puzzles, &options);
+    }
+
+    let input = source.read(puzzles[0].year, puzzles[0].day)?;
+    let results = runner::solve_variant(puzzles[0], &input, options.part, variant)?;
+    print!("{}", runner::render(&results, options.format.unwrap_or(OutputFormat::Table)));
+    Ok(())
+}
+
+/* Solves every day in parallel. Tables are summarized with one row per day, where the days that fail
+ * (including the ones whose solver panics) are marked as failed; their errors are reported after the
+ * results of the others */
+fn run_all(puzzles: &[&Puzzle], options: &Options) -> Result<(), Box<dyn Error>> {
+    let solved = runner::solve_parallel(puzzles, options.part, |puzzle| InputSource::Default.read(puzzle.year, puzzle.day));
+
+    let mut results = Vec::new();
+    let mut failed = Vec::new();
+    for (puzzle, result) in puzzles.iter().zip(solved) {
+        match result {
+            Ok(day_results) => results.extend(day_results),
+            Err(err) => {
+                eprintln!("{err}");
+                failed.push((puzzle.day, puzzle.title));
+            },
+        }
+    }
+
+    match options.format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Table => print!("{}", runner::render_summary(&results, &failed)),
+        format => print!("{}", runner::render(&results, format)),
+    }
+    match failed.len() {
+        0 => Ok(()),
+        failures => Err(format!("{failures} of {} days failed", puzzles.len()).into()),
+    }
+}
+
+fn bench(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day", "--input", "--all", "--runs", "--save", "--baseline"])?;
+    let source = options.input.clone().unwrap_or(InputSource::Default);
+    let runs = options.runs.unwrap_or(DEFAULT_BENCH_RUNS);
+    let baseline = options.baseline.as_deref().map(bench::load).transpose()?;
+
+    let mut measurements = Vec::new();
+    for puzzle in selected_puzzles(&options)? {
+        let input = source.read(puzzle.year, puzzle.day)?;
+        measurements.extend(bench::bench(puzzle, &input, runs)?);
+    }
+    print!("{}", bench::render_table(&measurements, baseline.as_deref()));
+
+    if let Some(path) = &options.save {
+        bench::save(path, &measurements).map_err(|err| format!("couldn't save {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn verify(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut options = parse_options(args, &["--year", "--day", "--record"])?;
+    options.all = options.day.is_none(); // Every day is verified by default
+
+    let path = answers::default_path(options.year());
+    let mut answers = Answers::load(&path)?;
+    let mut wrong = 0;
+    let mut recorded = 0;
+
+    for puzzle in selected_puzzles(&options)? {
+        let input = InputSource::Default.read(puzzle.year, puzzle.day)?;
+
+        for result in runner::solve(puzzle, &input, None)? {
+            let (day, part) = (result.day, result.part);
+            match answers.verify(day, part, &result.answer) {
+                Verdict::Correct => println!("day {day:>2} part {part}: ok"),
+                Verdict::Wrong { expected } => {
+                    println!("day {day:>2} part {part}: WRONG\n  expected:\n{expected}\n  got:\n{}", result.answer);
+                    wrong += 1;
+                },
+                Verdict::Unrecorded if options.record => {
+                    println!("day {day:>2} part {part}: recorded");
+                    answers.insert(day, part, result.answer);
+                    recorded += 1;
+                },
+                Verdict::Unrecorded => println!("day {day:>2} part {part}: not recorded"),
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(&path).map_err(|err| format!("couldn't save {}: {err}", path.display()))?;
+    }
+    if wrong > 0 {
+        return Err(format!("{wrong} answer(s) changed").into());
+    }
+    Ok(())
+}
+
+fn fetch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day"])?;
+    let (year, day) = (options.year(), options.day.ok_or("--day is required")?);
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day '{day}'").into());
+    }
+
+    let client = Client::new(ClientConfig::from_env()?);
+    let destination = input::default_path(year, day);
+
+    match client.fetch_input(year, day, &destination)? {
+        Fetched::Downloaded => println!("Downloaded the input of day {day} of {year} to {}", destination.display()),
+        Fetched::Cached => println!("The input of day {day} of {year} is already at {}", destination.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day", "--part"])?;
+    let day = options.day.ok_or("--day is required")?;
+    let part = options.part.ok_or("--part is required")?;
+    let puzzle = find_puzzle(options.year(), day)?;
+    let year = puzzle.year;
+
+    let input = InputSource::Default.read(year, day)?;
+    let answer = runner::solve(puzzle, &input, Some(part))?.remove(0).answer;
+
+    let answers_path = answers::default_path(year);
+    let rejections_path = submit::default_rejections_path(year);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut rejections = Rejections::load(&rejections_path)?;
+
+    let client = Client::new(ClientConfig::from_env()?);
+    let submitted = submit::submit(&client, year, day, part, &answer, &mut answers, &mut rejections)?;
+    match submitted {
+        None => println!("Day {day} part {part}: {answer} was already accepted"),
+        Some(Submitted::Correct) => println!("Day {day} part {part}: {answer} is the right answer"),
+        Some(Submitted::TooHigh) => println!("Day {day} part {part}: {answer} is too high"),
+        Some(Submitted::TooLow) => println!("Day {day} part {part}: {answer} is too low"),
+        Some(Submitted::Wrong) => println!("Day {day} part {part}: {answer} is wrong"),
+        Some(Submitted::Wait(delay)) => println!("An answer was sent too recently, try again in {}s", delay.as_secs()),
+        Some(Submitted::AlreadySolved) => println!("Day {day} part {part} is already solved, or its previous part isn't"),
+    }
+
+    match submitted {
+        Some(Submitted::Correct) => answers.save(&answers_path).map_err(|err| format!("couldn't save {}: {err}", answers_path.display()))?,
+        Some(Submitted::TooHigh | Submitted::TooLow | Submitted::Wrong) => {
+            rejections.save(&rejections_path).map_err(|err| format!("couldn't save {}: {err}", rejections_path.display()))?
+        },
+        _ => (),
+    }
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day", "--title"])?;
+    let (year, day) = (options.year(), options.day.ok_or("--day is required")?);
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day '{day}'").into());
+    }
+    let title = options.title.unwrap_or_else(|| format!("Day {day}"));
+
+    let root = &files::crate_path("");
+    for path in scaffold::create_day(root, year, day, &title)? {
+        println!("Wrote {}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
+
+fn watch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day"])?;
+    let day = options.day.ok_or("--day is required")?;
+    let puzzle = find_puzzle(options.year(), day)?;
+
+    println!("Watching day {day} of {}: {} (Ctrl-C to stop)", puzzle.year, puzzle.title);
+    let mut snapshot = None;
+    let mut previous = None;
+    loop {
+        let current = watch::Snapshot::take(puzzle);
+        if snapshot.as_ref() != Some(&current) {
+            snapshot = Some(current);
+
+            let outcome = watch::evaluate(puzzle);
+            println!();
+            print!("{}", watch::render_changes(previous.as_ref(), &outcome));
+            previous = Some(outcome);
+        }
+        thread::sleep(watch::POLL_INTERVAL);
+    }
+}
+
+fn status(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--calendar"])?;
+
+    // Unwritten solvers panic, which only means they're missing
+    let statuses = runner::without_panic_messages(|| status::year_status(options.year()))?;
+    match options.calendar {
+        true => print!("{}", status::render_calendar(&statuses)),
+        false => print!("{}", status::render_table(&statuses)),
+    }
+    Ok(())
+}
+
+fn animate(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let options = parse_options(args, &["--year", "--day", "--part", "--input", "--fps", "--step", "--no-colour", "--export"])?;
+    let day = options.day.ok_or("--day is required")?;
+    let puzzle = find_puzzle(options.year(), day)?;
+    let part = options.part.unwrap_or(Part::One);
+
+    let input = options.input.clone().unwrap_or(InputSource::Default).read(puzzle.year, day)?;
+    let frames = puzzle.animate(&input, part).ok_or(format!("day {day} of {} has no animation", puzzle.year))??;
+    let fps = options.fps.unwrap_or(DEFAULT_FPS);
+    let colour = !options.no_colour && env::var_os("NO_COLOR").is_none();
+
+    if let Some(path) = &options.export {
+        let title = format!("{} day {day}: {}, part {part}", puzzle.year, puzzle.title);
+        let write_error = |err: io::Error| format!("couldn't write {}: {err}", path.display());
+
+        // Streamed to the file: recordings of long simulations don't fit in memory
+        let mut recording = BufWriter::new(File::create(path).map_err(write_error)?);
+        let frames = visualize::write_asciicast(frames, fps, colour, &title, &mut recording).map_err(write_error)?;
+        recording.flush().map_err(write_error)?;
+        println!("Wrote {frames} frames to {}", path.display());
+        return Ok(());
+    }
+
+    let play_options = PlayOptions { fps, colour, paused: options.step };
+    visualize::play(frames, play_options, &visualize::stdin_controls(), &mut io::stdout().lock())?;
+    Ok(())
+}
+
+// Tools for the inventory of day 1 of 2022
+fn calories(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args.first().map(String::as_str) {
+        Some("stats") => {
+            let options = parse_options(&args[1..], &["--input", "--bins", "--format"])?;
+            let input = options.input.clone().unwrap_or(InputSource::Default).read(Day1::YEAR, Day1::DAY)?;
+            let statistics = Statistics::of(&Day1::parse(&input)?, options.bins.unwrap_or(DEFAULT_BINS));
+
+            match options.format.unwrap_or(OutputFormat::Table) {
+                OutputFormat::Table => print!("{}", statistics.render_text()),
+                OutputFormat::Json => print!("{}", statistics.render_json()),
+                OutputFormat::Csv => return Err("the statistics are shown as a table or as JSON".into()),
+            }
+            Ok(())
+        },
+        Some("rebalance") => {
+            let options = parse_options(&args[1..], &["--input", "--method"])?;
+            let input = options.input.clone().unwrap_or(InputSource::Default).read(Day1::YEAR, Day1::DAY)?;
+            let rebalancing = day01::rebalance(&Day1::parse(&input)?, options.method.unwrap_or(Method::Auto));
+            print!("{}", rebalancing.render());
+            Ok(())
+        },
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("status") => status(&args[1..]),
+        Some("animate") => animate(&args[1..]),
+        Some("calories") => calories(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,409 @@
+//! Client for the Advent of Code website.
+//!
+//! Requests are authenticated with the session cookie of a logged-in user, identify this
+//! project through their User-Agent and are spaced out so the website isn't hammered. The base
+//! URL can be changed, which lets tests run against a local server.
+
+use std::{env, fs, path::{Path, PathBuf}, thread, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use crate::{files::crate_path, AocError, Part};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/leleosilva/Advent-of-Code-2022)",
+);
+
+/// Minimum time between two requests to the website
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Settings of the client
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: String,
+    pub min_interval: Duration,
+
+    /* File storing when the last request was sent, so the interval is respected across different
+     * runs of the program */
+    pub throttle_path: PathBuf,
+
+    // Source of the current time, used to space out the requests
+    pub clock: fn() -> SystemTime,
+}
+
+impl ClientConfig {
+
+    /* Reads the configuration from the environment:
+     *   - AOC_SESSION holds the session token or, if unset, the file AOC_SESSION_FILE
+     *     (by default, '.aoc-session' at the crate root) does
+     *   - AOC_BASE_URL replaces the website URL */
+    pub fn from_env() -> Result<ClientConfig, AocError> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let path = env::var_os("AOC_SESSION_FILE")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| crate_path(".aoc-session"));
+                fs::read_to_string(&path).map_err(|_| {
+                    AocError::Config(format!("no session token: set AOC_SESSION or write it to {}", path.display()))
+                })?
+            },
+        };
+
+        let session = session.trim().to_owned();
+        if session.is_empty() {
+            return Err(AocError::Config("the session token is empty".to_owned()));
+        }
+
+        Ok(ClientConfig {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned()),
+            session,
+            min_interval: MIN_REQUEST_INTERVAL,
+            throttle_path: env::temp_dir().join("aoc-2022-last-request"),
+            clock: SystemTime::now,
+        })
+    }
+}
+
+/// What [`Client::fetch_input`] did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Reply of the website to a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Submitted {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling whether the answer is too high or too low
+    Wrong,
+    /// An answer was submitted too recently; the next one can be sent after this delay
+    Wait(Duration),
+    /// The part was already solved (or the previous part isn't yet)
+    AlreadySolved,
+}
+
+pub struct Client {
+    config: ClientConfig,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client { config, agent }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url.trim_end_matches('/'))
+    }
+
+    /* Computes how long to wait for the minimum interval since the last request to pass, and records
+     * the new request as sent once the wait is over */
+    fn reserve_request(&self) -> Duration {
+        let now = (self.config.clock)().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let last_request = fs::read_to_string(&self.config.throttle_path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+
+        let wait = last_request
+            .and_then(|last| (last + self.config.min_interval).checked_sub(now))
+            .unwrap_or_default();
+
+        // Rounding up, so the next request can't be sent a fraction of a millisecond too early.
+        // Failing to record the request only weakens the throttling; it shouldn't stop the request
+        let _ = fs::write(&self.config.throttle_path, (now + wait).as_nanos().div_ceil(1_000_000).to_string());
+        wait
+    }
+
+    // Waits until the minimum interval since the last request has passed
+    fn throttle(&self) {
+        thread::sleep(self.reserve_request());
+    }
+
+    // Sends an authenticated GET request and returns the body of the response
+    pub(crate) fn get(&self, path: &str) -> Result<String, AocError> {
+        let url = self.url(path);
+        self.throttle();
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.config.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    // Sends an authenticated POST request with a form and returns the body of the response
+    pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        let url = self.url(path);
+        self.throttle();
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.config.session))
+            .send_form(form);
+        read_response(&url, response)
+    }
+
+    /// Submits the answer of a part of a day of `year`
+    pub fn submit_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Submitted, AocError> {
+        let path = format!("/{year}/day/{day}/answer");
+        let body = self.post(&path, &[("level", &part.to_string()), ("answer", answer)])?;
+
+        parse_submitted(&body).ok_or_else(|| AocError::Http {
+            url: self.url(&path),
+            reason: "the reply to the answer wasn't understood".to_owned(),
+        })
+    }
+
+    /* Downloads the input of a day of 'year' to 'destination'.
+     * An input that was already downloaded is never requested again */
+    pub fn fetch_input(&self, year: u16, day: u8, destination: &Path) -> Result<Fetched, AocError> {
+        if destination.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+
+        // Writing to a temporary file first, so an interrupted download never looks like a cached input
+        let io_error = |error| AocError::Io { source: destination.display().to_string(), error };
+        if let Some(dir) = destination.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = destination.with_extension("part");
+        fs::write(&partial, input).map_err(io_error)?;
+        fs::rename(&partial, destination).map_err(io_error)?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
+
+/* Reads the reply of the website to an answer, from its sentences like "That's not the right
+ * answer; your answer is too high" or "You have 1m 5s left to wait" */
+pub fn parse_submitted(html: &str) -> Option<Submitted> {
+    if html.contains("That's the right answer") {
+        Some(Submitted::Correct)
+    }
+    else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Some(Submitted::TooHigh)
+        }
+        else if html.contains("your answer is too low") {
+            Some(Submitted::TooLow)
+        }
+        else {
+            Some(Submitted::Wrong)
+        }
+    }
+    else if html.contains("You gave an answer too recently") {
+        let (_, rest) = html.split_once("You have ")?;
+        let (delay, _) = rest.split_once(" left to wait")?;
+        parse_delay(delay).map(Submitted::Wait)
+    }
+    else if html.contains("Did you already complete it") {
+        Some(Submitted::AlreadySolved)
+    }
+    else {
+        None
+    }
+}
+
+// Parses a delay like '1m 5s' or '30s'
+fn parse_delay(delay: &str) -> Option<Duration> {
+    delay.split_whitespace().try_fold(Duration::ZERO, |total, amount| {
+        let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+        let seconds = match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return None,
+        };
+        Some(total + Duration::from_secs(value.parse::<u64>().ok()? * seconds))
+    })
+}
+
+// Turns a response into its body, or into an error explaining why the request failed
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, AocError> {
+    let error = |reason: String| AocError::Http { url: url.to_owned(), reason };
+
+    match response {
+        Ok(response) => response.into_string().map_err(|err| error(err.to_string())),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = match status {
+                404 => "the puzzle isn't available (yet?)".to_owned(),
+                400 | 500 if body.contains("log in") => "the session token is invalid or expired".to_owned(),
+                _ => format!("status {status}: {}", body.trim()),
+            };
+            Err(error(reason))
+        },
+        Err(err) => Err(error(err.to_string())),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{io::{BufRead, BufReader, Read, Write}, net::TcpListener, process};
+
+    // Request received by the stub server
+    #[derive(Debug)]
+    pub(crate) struct Request {
+        pub(crate) request_line: String,
+        pub(crate) headers: Vec<String>,
+    }
+
+    /* Starts a local HTTP server answering each incoming request with the next response of the list.
+     * Returns its base URL and a handle yielding the requests once they have all been answered */
+    pub(crate) fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(header.trim().to_owned());
+                }
+
+                let content_length = headers
+                    .iter()
+                    .find_map(|h| h.to_ascii_lowercase().strip_prefix("content-length: ").map(|l| l.parse().unwrap()))
+                    .unwrap_or(0);
+                reader.read_exact(&mut vec![0; content_length]).unwrap(); // Draining the body before answering
+
+                write!(stream, "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+                requests.push(Request { request_line: request_line.trim().to_owned(), headers });
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    // Empty folder in the system's temporary directory, unique to a test
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2022-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    pub(crate) fn test_client(base_url: String, dir: &Path, min_interval: Duration) -> Client {
+        Client::new(ClientConfig {
+            base_url,
+            session: "abc123".to_owned(),
+            min_interval,
+            throttle_path: dir.join("last-request"),
+            clock: SystemTime::now,
+        })
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let dir = temp_dir("fetch");
+        let (base_url, server) = stub_server(vec![(200, "1000\n2000\n")]);
+        let client = test_client(base_url, &dir, Duration::ZERO);
+
+        let destination = dir.join("input").join("day1.txt");
+        assert_eq!(client.fetch_input(2022, 1, &destination).unwrap(), Fetched::Downloaded);
+        assert_eq!(client.fetch_input(2022, 1, &destination).unwrap(), Fetched::Cached); // The server only answers once
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].request_line, "GET /2022/day/1/input HTTP/1.1");
+        assert!(requests[0].headers.iter().any(|h| h == "Cookie: session=abc123"));
+        assert!(requests[0].headers.iter().any(|h| h.eq_ignore_ascii_case(&format!("user-agent: {USER_AGENT}"))));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = temp_dir("fetch-errors");
+        let (base_url, server) = stub_server(vec![
+            (404, "Not Found"),
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+        ]);
+        let client = test_client(base_url, &dir, Duration::ZERO);
+
+        let err = client.fetch_input(2022, 25, &dir.join("day25.txt")).unwrap_err();
+        assert!(err.to_string().ends_with("the puzzle isn't available (yet?)"));
+        let err = client.fetch_input(2022, 1, &dir.join("day1.txt")).unwrap_err();
+        assert!(err.to_string().ends_with("the session token is invalid or expired"));
+
+        assert!(!dir.join("day1.txt").exists());
+        server.join().unwrap();
+    }
+
+    // The clock is stopped 1000 seconds after the epoch, so the requests are reserved back to back
+    #[test]
+    fn test_requests_are_throttled() {
+        let dir = temp_dir("throttle");
+        let mut config = test_client(String::new(), &dir, Duration::from_millis(300)).config;
+        config.clock = || UNIX_EPOCH + Duration::from_secs(1000);
+        let client = Client::new(config);
+        let last_request = || fs::read_to_string(dir.join("last-request")).unwrap();
+
+        assert_eq!(client.reserve_request(), Duration::ZERO);
+        assert_eq!(last_request(), "1000000");
+        assert_eq!(client.reserve_request(), Duration::from_millis(300));
+        assert_eq!(last_request(), "1000300");
+        assert_eq!(client.reserve_request(), Duration::from_millis(600));
+        assert_eq!(last_request(), "1000600");
+
+        // A request recorded long ago doesn't delay the next one
+        fs::write(dir.join("last-request"), "1").unwrap();
+        assert_eq!(client.reserve_request(), Duration::ZERO);
+        assert_eq!(last_request(), "1000000");
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let dir = temp_dir("submit");
+        let (base_url, server) = stub_server(vec![
+            (200, "<article><p>That's the right answer!  You are one gold star closer.</p></article>"),
+            (200, "<article><p>That's not the right answer; your answer is too low.  Please wait one minute.</p></article>"),
+            (200, "<article><p>You gave an answer too recently.  You have 1m 5s left to wait.</p></article>"),
+            (200, "<html>Maintenance</html>"),
+        ]);
+        let client = test_client(base_url, &dir, Duration::ZERO);
+
+        assert_eq!(client.submit_answer(2022, 1, Part::One, "69501").unwrap(), Submitted::Correct);
+        assert_eq!(client.submit_answer(2022, 1, Part::Two, "12").unwrap(), Submitted::TooLow);
+        assert_eq!(client.submit_answer(2022, 1, Part::Two, "13").unwrap(), Submitted::Wait(Duration::from_secs(65)));
+        assert!(client.submit_answer(2022, 1, Part::Two, "14").is_err());
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[1].request_line, "POST /2022/day/1/answer HTTP/1.1");
+        assert!(requests[1].headers.iter().any(|h| h == "Cookie: session=abc123"));
+    }
+
+    #[test]
+    fn test_parse_submitted() {
+        assert_eq!(parse_submitted("That's not the right answer; your answer is too high."), Some(Submitted::TooHigh));
+        assert_eq!(parse_submitted("That's not the right answer.  If you're stuck..."), Some(Submitted::Wrong));
+        assert_eq!(parse_submitted("You don't seem to be solving the right level.  Did you already complete it?"), Some(Submitted::AlreadySolved));
+        assert_eq!(parse_submitted("You gave an answer too recently; You have 30s left to wait."), Some(Submitted::Wait(Duration::from_secs(30))));
+        assert_eq!(parse_submitted("You gave an answer too recently; You have soon left to wait."), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Errors reported while loading, downloading and parsing puzzle inputs.
+
+use std::{error::Error, fmt, io};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// A line of the input doesn't have the shape the parser expects
+    Parse {
+        day: u8,
+        line: usize, // 1-based
+        text: String,
+        expected: String,
+    },
+    /// The input as a whole is unusable (empty, missing a section, inconsistent...)
+    Invalid {
+        day: u8,
+        reason: String,
+    },
+    /// The input couldn't be read
+    Io {
+        source: String,
+        error: io::Error,
+    },
+    /// A request to the Advent of Code website failed
+    Http {
+        url: String,
+        reason: String,
+    },
+    /// The client isn't configured properly (no session token, for instance)
+    Config(String),
+}
+
+impl AocError {
+    pub fn parse(day: u8, line: usize, text: &str, expected: impl Into<String>) -> AocError {
+        AocError::Parse {
+            day,
+            line,
+            text: text.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn invalid(day: u8, reason: impl Into<String>) -> AocError {
+        AocError::Invalid { day, reason: reason.into() }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse { day, line, text, expected } => {
+                write!(f, "day {day}, line {line}: expected {expected}, found '{text}'")
+            },
+            AocError::Invalid { day, reason } => write!(f, "day {day}: {reason}"),
+            AocError::Io { source, error } => write!(f, "couldn't read {source}: {error}"),
+            AocError::Http { url, reason } => write!(f, "request to {url} failed: {reason}"),
+            AocError::Config(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Example inputs from the puzzle descriptions, with their expected answers.
+//!
+//! Examples live in `examples/<year>/dayN/*.txt`. Each file starts with the expected answers, written
+//! like in `answers.toml` but without the table header, followed by a `---` line and the input:
+//!
+//! ```text
+//! part1 = 24000
+//! part2 = 45000
+//! ---
+//! 1000
+//! 2000
+//! ```
+//!
+//! A part without an expected answer isn't checked for that example.
+
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::{answers::Answers, files::crate_path, Answer, Part};
+
+const SEPARATOR: &str = "---\n";
+
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+impl Example {
+    /// Parses the contents of an example file of `day`
+    pub fn parse(day: u8, name: &str, text: &str) -> Result<Example, String> {
+        let (header, input) = if let Some(input) = text.strip_prefix(SEPARATOR) {
+            ("", input)
+        } else {
+            text
+                .split_once(&format!("\n{SEPARATOR}"))
+                .ok_or_else(|| format!("{name}: expected a '---' line between the answers and the input"))?
+        };
+
+        Ok(Example {
+            name: name.to_owned(),
+            input: input.to_owned(),
+            expected: Answers::parse_for_day(day, header).map_err(|err| format!("{name}: {err}"))?,
+        })
+    }
+
+    /// Expected answer of a part, if the example has one
+    pub fn expected(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.expected.get(day, part)
+    }
+}
+
+// Folder holding the examples of a day, resolved from the crate root
+pub fn examples_dir(year: u16, day: u8) -> PathBuf {
+    crate_path("examples")
+        .join(year.to_string())
+        .join(format!("day{day}"))
+}
+
+/// Loads every example of a day, ordered by file name. A day without examples has none
+pub fn load_all(year: u16, day: u8) -> Result<Vec<Example>, String> {
+    let dir = examples_dir(year, day);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = fs::read_dir(&dir)
+        .and_then(|entries| entries.map(|entry| entry.map(|e| e.path())).collect::<Result<Vec<PathBuf>, _>>())
+        .map_err(|err| format!("couldn't list {}: {err}", dir.display()))?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths.iter().map(|path| load_file(day, path)).collect()
+}
+
+/// Loads the example `examples/<year>/dayN/<name>.txt`
+pub fn load(year: u16, day: u8, name: &str) -> Result<Example, String> {
+    load_file(day, &examples_dir(year, day).join(format!("{name}.txt")))
+}
+
+fn load_file(day: u8, path: &Path) -> Result<Example, String> {
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    let text = fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+    Example::parse(day, &name, &text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse(1, "example", "part1 = 3\n---\n1\n2\n\n3\n").unwrap();
+        assert_eq!(example.input, "1\n2\n\n3\n");
+        assert_eq!(example.expected(1, Part::One), Some(&Answer::Integer(3)));
+        assert_eq!(example.expected(1, Part::Two), None);
+
+        assert!(Example::parse(1, "example", "part1 = 3\n1\n2\n").is_err());
+    }
+
+    // Every registered solver is run against every example of its day
+    #[test]
+    fn test_examples() {
+        for puzzle in registry::all_puzzles() {
+            let examples = load_all(puzzle.year, puzzle.day).unwrap();
+            assert!(!examples.is_empty(), "day {} has no examples", puzzle.day);
+
+            for example in examples {
+                let parsed = puzzle
+                    .parse(&example.input)
+                    .unwrap_or_else(|err| panic!("day {} {}: {err}", puzzle.day, example.name));
+
+                for (variant, part) in puzzle.variants().into_iter().flat_map(|variant| Part::BOTH.map(|part| (variant, part))) {
+                    if let Some(expected) = example.expected(puzzle.day, part) {
+                        let answer = parsed.solve_variant(variant, part).unwrap();
+                        assert_eq!(&answer, expected, "day {} {} part {part} ({variant})", puzzle.day, example.name);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Files of the crate: inputs, examples, answers and settings, all found from the crate root.
+
+use std::{fs, io, path::{Path, PathBuf}};
+
+// Path of a file or folder, relative to the crate root
+pub fn crate_path(relative: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+/* Reads a file that may not exist yet, like the answers of a year before the first one is recorded.
+ * A missing file gives 'None' rather than an error */
+pub fn read_optional(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("couldn't read {}: {err}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_optional() {
+        assert!(read_optional(&crate_path("Cargo.toml")).unwrap().unwrap().contains("aoc-2022"));
+        assert_eq!(read_optional(&crate_path("answers").join("1999.toml")), Ok(None));
+        assert!(read_optional(&crate_path("src")).is_err()); // A folder can't be read
+    }
+}
@@ -0,0 +1,273 @@
+//! Two-dimensional grids shared by the puzzles.
+//!
+//! [`Grid`] is a dense, fixed-size grid indexed by `(x, y)`, with `x` growing to the right and `y`
+//! growing downwards (like the rows of a puzzle input). [`SparseGrid`] only stores the cells that
+//! were set, indexed by signed [`Point`]s, which suits unbounded grids like the rope of day 9.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{point::Point, AocError};
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>, // Row by row: the cell (x, y) is at 'x + y * width'
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid where every cell holds `value`
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /* Parses a grid with one row per line, converting each character with 'cell'.
+     * Every row must have the same length, and 'cell' must accept every character */
+    pub fn parse(day: u8, text: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, AocError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (line_idx, row) in text.lines().enumerate() {
+            let error = |expected: &str| AocError::parse(day, line_idx + 1, row, expected);
+
+            let row_width = row.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(error(&format!("a row of {} cells", width.unwrap())));
+            }
+            for c in row.chars() {
+                cells.push(cell(c).ok_or_else(|| error(&format!("valid cells, but '{c}' isn't one")))?);
+            }
+            height += 1;
+        }
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Cell at `(x, y)`, or `None` outside of the grid
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[x + y * self.width])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y).then(|| &mut self.cells[x + y * self.width])
+    }
+
+    /// Replaces the cell at `(x, y)`, returning its previous value, or `None` outside of the grid
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        self.get_mut(x, y).map(|cell| std::mem::replace(cell, value))
+    }
+
+    // Positions reached from (x, y) by the given offsets, skipping the ones outside of the grid
+    fn offsets(&self, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    /// Positions of the (up to 4) cells sharing a side with `(x, y)`
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the (up to 8) cells sharing a side or a corner with `(x, y)`
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_8)
+    }
+
+    /// Cells of the row `y`, from left to right
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.rows().nth(y).into_iter().flatten()
+    }
+
+    /// Cells of the column `x`, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let x = if x < self.width { x } else { self.cells.len() }; // An invalid column yields nothing
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.iter().enumerate().map(|(idx, cell)| ((idx % self.width, idx / self.width), cell))
+    }
+
+    /// Renders the grid as text, one line per row, drawing each cell with `draw`
+    pub fn render(&self, draw: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&draw).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of plain characters
+    pub fn parse_chars(day: u8, text: &str) -> Result<Grid<char>, AocError> {
+        Grid::parse(day, text, Some)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|c| *c))
+    }
+}
+
+/// Unbounded grid storing only the cells that were set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new() }
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    /// Sets the cell at `position`, returning its previous value if it was set
+    pub fn set(&mut self, position: Point, value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    /// Number of cells that were set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(position, cell)| (*position, cell))
+    }
+
+    /// Smallest and largest corners of the rectangle holding every set cell
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let xs = self.cells.keys().map(|position| position.x);
+        let ys = self.cells.keys().map(|position| position.y);
+        Some((Point::new(xs.clone().min()?, ys.clone().min()?), Point::new(xs.max()?, ys.max()?)))
+    }
+
+    /// Copies the set cells into a dense grid, filling the rest of their bounds with `empty`
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return Grid::new(0, 0, empty);
+        };
+
+        let mut grid = Grid::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, empty);
+        for (position, cell) in &self.cells {
+            grid.set((position.x - min.x) as usize, (position.y - min.y) as usize, cell.clone());
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "ab\ncd\nef";
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = Grid::parse_chars(1, TEXT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.to_string(), TEXT);
+
+        let digits = Grid::parse(1, "12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.render(|d| if d % 2 == 0 { '#' } else { '.' }), ".#\n.#");
+
+        let err = Grid::parse_chars(1, "ab\nc").unwrap_err();
+        assert_eq!(err.to_string(), "day 1, line 2: expected a row of 2 cells, found 'c'");
+        assert!(Grid::parse(1, "1x", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_bounds_checked_access() {
+        let mut grid = Grid::parse_chars(1, TEXT).unwrap();
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid.get(2, 0), None);
+
+        assert_eq!(grid.set(0, 1, 'x'), Some('c'));
+        assert_eq!(grid.set(0, 3, 'x'), None);
+        assert_eq!(grid.to_string(), "ab\nxd\nef");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), [(1, 1), (2, 1), (1, 2)]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_chars(1, TEXT).unwrap();
+        assert_eq!(grid.row(1).collect::<String>(), "cd");
+        assert_eq!(grid.row(3).count(), 0);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.column(2).count(), 0);
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(), ["ace", "bdf"]);
+        assert_eq!(grid.iter().nth(3), Some(((1, 1), &'d')));
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut sparse = SparseGrid::new();
+        assert_eq!(sparse.bounds(), None);
+
+        sparse.set(Point::new(-1, 2), '#');
+        sparse.set(Point::new(1, 0), '#');
+        assert_eq!(sparse.set(Point::new(1, 0), 'o'), Some('#'));
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.bounds(), Some((Point::new(-1, 0), Point::new(1, 2))));
+        assert_eq!(sparse.to_grid('.').to_string(), "..o\n...\n#..");
+    }
+}
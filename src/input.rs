@@ -0,0 +1,76 @@
+//! Loading puzzle inputs at runtime.
+//!
+//! An input can come from an explicit file, from stdin (`-`) or, by default, from
+//! `input/<year>/dayN.txt` under the crate root, no matter the current directory.
+
+use std::{fs, io::{self, Read}, path::{Path, PathBuf}};
+
+use crate::{files::crate_path, AocError};
+
+/// Where the input of a puzzle is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command-line argument: `-` means stdin, anything else is a file path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the whole input of `day` of `year` from this source
+    pub fn read(&self, year: u16, day: u8) -> Result<String, AocError> {
+        match self {
+            InputSource::Default => read_file(&default_path(year, day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| AocError::Io { source: "stdin".to_owned(), error })?;
+                Ok(input)
+            },
+        }
+    }
+}
+
+// Path of the default input of a day, resolved from the crate root
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    crate_path("input")
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+fn read_file(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|error| AocError::Io { source: path.display().to_string(), error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("notes.txt"), InputSource::File(PathBuf::from("notes.txt")));
+    }
+
+    #[test]
+    fn test_default_path_is_independent_of_current_dir() {
+        let path = default_path(2022, 1);
+        assert!(path.is_absolute() && path.ends_with("input/2022/day1.txt"));
+        assert_eq!(InputSource::Default.read(2022, 1).unwrap(), InputSource::File(path).read(2022, 1).unwrap());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = InputSource::from_arg("no/such/input.txt").read(2022, 1).unwrap_err();
+        assert!(err.to_string().starts_with("couldn't read no/such/input.txt"));
+    }
+}
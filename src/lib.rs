@@ -0,0 +1,153 @@
+//! Advent of Code solutions.
+//!
+//! Each year has its own module (like [`y2022`]), where each day lives in its own module and
+//! implements [`Solution`]. Solvers are listed in [`registry`], which the `aoc` runner binary uses
+//! to find them.
+
+use std::{fmt, str::FromStr};
+
+pub use error::AocError;
+
+pub mod y2022;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod files;
+pub mod grid;
+pub mod input;
+pub mod parsing;
+pub mod point;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod status;
+pub mod submit;
+pub mod visualize;
+pub mod watch;
+
+/// One of the two parts of a puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Part, String> {
+        match part {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("unknown part '{part}', expected 1 or 2")),
+        }
+    }
+}
+
+/// Answer produced by one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Image made of text rows separated by '\n', like the CRT display of day 10
+    Bitmap(String),
+}
+
+impl Answer {
+    /// Name of the kind of answer, as used in machine-readable outputs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "string",
+            Answer::Bitmap(_) => "bitmap",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => value.fmt(f),
+            Answer::Text(text) | Answer::Bitmap(text) => f.pad(text),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// Alternative implementation of both parts of a [`Solution`], listed in [`Solution::VARIANTS`]
+pub struct Variant<I, A, B> {
+    /// Name selecting the variant, like `bitmask`
+    pub name: &'static str,
+    pub part_one: fn(&I) -> A,
+    pub part_two: fn(&I) -> B,
+}
+
+/// A puzzle solver: parses the raw input once and answers both parts from it.
+///
+/// Malformed input is reported by `parse`, so the parts can rely on a well-formed `Input`.
+pub trait Solution {
+    /// Year of the event
+    const YEAR: u16;
+    /// Day of the puzzle (1 to 25)
+    const DAY: u8;
+    /// Title of the puzzle, as shown on the website
+    const TITLE: &'static str;
+
+    /// Parsed representation of the puzzle input
+    type Input: 'static;
+    type PartOne: Into<Answer> + 'static;
+    type PartTwo: Into<Answer> + 'static;
+
+    /// Other ways of solving the parts, kept side by side with the default one. They must give the
+    /// same answers as `part_one` and `part_two`
+    const VARIANTS: &'static [Variant<Self::Input, Self::PartOne, Self::PartTwo>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
@@ -0,0 +1,190 @@
+//! Helpers for the shapes of input most puzzles share: lines of numbers, paragraphs separated by
+//! blank lines, and lines following a fixed pattern like `move {} from {} to {}`.
+//!
+//! Every helper keeps track of where it is in the input, so errors point at the line (and the
+//! column, for a field) that couldn't be parsed.
+
+use std::str::FromStr;
+
+use crate::AocError;
+
+/// A line of the input, with its 1-based line number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Block of consecutive non-blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Paragraph<'a> {
+    pub day: u8,
+    pub first_line: usize, // 1-based
+    pub text: &'a str,
+}
+
+/// Lines of `text`, numbered from 1
+pub fn lines(day: u8, text: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered_lines(day, text, 1)
+}
+
+fn numbered_lines(day: u8, text: &str, first_line: usize) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(move |(idx, text)| Line { day, number: first_line + idx, text })
+}
+
+/// Splits `text` into paragraphs separated by one or more blank (or whitespace-only) lines
+pub fn paragraphs(day: u8, text: &str) -> Vec<Paragraph<'_>> {
+    let mut paragraphs = Vec::new();
+    let mut current: Option<(usize, usize, usize)> = None; // (first line, start and end offsets)
+    let mut offset = 0;
+
+    for (idx, line) in text.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                paragraphs.push(Paragraph { day, first_line, text: &text[start..end] });
+            }
+        }
+        else {
+            let (first_line, start) = current.map_or((idx + 1, offset), |(first_line, start, _)| (first_line, start));
+            current = Some((first_line, start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, start, end)) = current {
+        paragraphs.push(Paragraph { day, first_line, text: &text[start..end] });
+    }
+    paragraphs
+}
+
+impl<'a> Paragraph<'a> {
+    /// Lines of the paragraph, numbered from their position in the whole input
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        numbered_lines(self.day, self.text, self.first_line)
+    }
+}
+
+impl<'a> Line<'a> {
+    /// The same line without leading and trailing whitespace
+    pub fn trim(self) -> Line<'a> {
+        Line { text: self.text.trim(), ..self }
+    }
+
+    /// Parse error on this line
+    pub fn error(&self, expected: impl Into<String>) -> AocError {
+        AocError::parse(self.day, self.number, self.text, expected)
+    }
+
+    // 1-based column where 'field' starts, if it's a slice of this line
+    fn column(&self, field: &str) -> usize {
+        let offset = (field.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() { offset + 1 } else { 1 }
+    }
+
+    /// Parses `field` (a slice of this line) as a number
+    pub fn number<T: FromStr>(&self, field: &str) -> Result<T, AocError> {
+        field.parse().map_err(|_| self.error(format!("a number at column {}", self.column(field))))
+    }
+
+    /* Every integer in the line, in order. A '-' right before the digits makes the integer negative,
+     * unless it follows a letter or a digit ('2-4' holds 2 and 4, 'x=-3' holds -3) */
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, AocError> {
+        let bytes = self.text.as_bytes();
+        let mut integers = Vec::new();
+        let mut idx = 0;
+
+        while idx < bytes.len() {
+            if !bytes[idx].is_ascii_digit() {
+                idx += 1;
+                continue;
+            }
+            let negative = idx > 0 && bytes[idx - 1] == b'-' && (idx < 2 || !bytes[idx - 2].is_ascii_alphanumeric());
+            let start = if negative { idx - 1 } else { idx };
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            let field = &self.text[start..idx];
+            integers.push(field.parse().map_err(|_| self.error(format!("a number in range at column {}", start + 1)))?);
+        }
+        Ok(integers)
+    }
+
+    /* Matches the line against 'pattern', where each '{}' stands for a non-empty field, and returns
+     * the fields. Every other character of the pattern must appear as is in the line.
+     *
+     * Panics if 'pattern' doesn't hold exactly N placeholders, or if two of them are adjacent */
+    pub fn extract<const N: usize>(&self, pattern: &str) -> Result<[&'a str; N], AocError> {
+        let literals: Vec<&str> = pattern.split("{}").collect();
+        assert_eq!(literals.len(), N + 1, "the pattern '{pattern}' should hold {N} placeholders");
+
+        let error = || self.error(format!("a line matching '{pattern}'"));
+        let mut fields = [""; N];
+        let mut rest = self.text.strip_prefix(literals[0]).ok_or_else(error)?;
+
+        for (idx, literal) in literals[1..].iter().enumerate() {
+            let field = if idx == N - 1 { // The last field takes everything before the trailing literal
+                rest.strip_suffix(literal).ok_or_else(error)?
+            }
+            else {
+                assert!(!literal.is_empty(), "the pattern '{pattern}' has adjacent placeholders");
+                rest.split_once(literal).ok_or_else(error)?.0
+            };
+            if field.is_empty() {
+                return Err(error());
+            }
+            fields[idx] = field;
+            rest = &rest[field.len() + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(error());
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { day: 1, number: 3, text }
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let text = "\na\nb\n\n  \nc\r\n\r\nd\n";
+        let paragraphs = paragraphs(1, text);
+
+        assert_eq!(paragraphs.iter().map(|p| (p.first_line, p.text)).collect::<Vec<_>>(), [(2, "a\nb"), (6, "c"), (8, "d")]);
+        assert_eq!(paragraphs[0].lines().map(|line| line.number).collect::<Vec<_>>(), [2, 3]);
+        assert!(super::paragraphs(1, "\n \n").is_empty());
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(line("2-4,6-8").integers::<i32>().unwrap(), [2, 4, 6, 8]);
+        assert_eq!(line("x=-3, y=12: at -7").integers::<i64>().unwrap(), [-3, 12, -7]);
+        assert!(line("no numbers").integers::<u8>().unwrap().is_empty());
+
+        let err = line("a 300").integers::<u8>().unwrap_err();
+        assert_eq!(err.to_string(), "day 1, line 3: expected a number in range at column 3, found 'a 300'");
+    }
+
+    #[test]
+    fn test_extract() {
+        let instruction = line("move 1 from 2 to 3");
+        let [crates, from, to] = instruction.extract("move {} from {} to {}").unwrap();
+        assert_eq!((crates, from, to), ("1", "2", "3"));
+        assert_eq!(instruction.number::<u8>(to).unwrap(), 3);
+
+        assert_eq!(line("Monkey 12:").extract("Monkey {}:").unwrap(), ["12"]);
+        assert!(line("move  from 2 to 3").extract::<3>("move {} from {} to {}").is_err());
+        assert!(line("Monkey 12").extract::<1>("Monkey {}:").is_err());
+
+        let sections = line("2-x,6-8");
+        let [_, end, _, _] = sections.extract("{}-{},{}-{}").unwrap();
+        let err = sections.number::<i32>(end).unwrap_err();
+        assert_eq!(err.to_string(), "day 1, line 3: expected a number at column 3, found '2-x,6-8'");
+    }
+}
@@ -0,0 +1,181 @@
+//! Points and directions on a 2D plane.
+//!
+//! Like in [`crate::grid`], `x` grows to the right and `y` grows downwards, so [`Direction::Up`]
+//! decrements `y`.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Position (or displacement) on the plane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// Sign of each coordinate: a step of at most 1 on each axis towards this displacement
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Distance when moving in 8 directions (diagonal moves count as one step)
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Distance when moving in 4 directions
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        Point::new(x, y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// One of the four orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Displacement of a single step in this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+// Accepts the initials used by the puzzles ('U', 'D', 'L', 'R') as well as the full names
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(direction: &str) -> Result<Direction, String> {
+        match direction {
+            "U" | "up" => Ok(Direction::Up),
+            "D" | "down" => Ok(Direction::Down),
+            "L" | "left" => Ok(Direction::Left),
+            "R" | "right" => Ok(Direction::Right),
+            _ => Err(format!("unknown direction '{direction}', expected U, D, L or R")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point::new(1, -2);
+        assert_eq!(point + Point::new(2, 3), Point::new(3, 1));
+        assert_eq!(point - Point::new(2, 3), Point::new(-1, -5));
+        assert_eq!(point * 3, Point::new(3, -6));
+        assert_eq!(-point, Point::new(-1, 2));
+
+        point += Direction::Right.offset();
+        point -= Direction::Up.offset();
+        assert_eq!(point, Point::new(2, -1));
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(a), 0);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("left".parse(), Ok(Direction::Left));
+        assert!("X".parse::<Direction>().is_err());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.offset() + direction.opposite().offset(), Point::ORIGIN);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! List of every solver known to the runner.
+//!
+//! Each year registers its solutions in its own `PUZZLES` list (like [`crate::y2022::PUZZLES`]),
+//! and the years are listed in [`YEARS`]; the runner (and any other tool) looks days up here
+//! instead of matching on day numbers.
+
+use crate::{
+    visualize::{Frames, Visualize},
+    Answer, AocError, Part, Solution,
+};
+
+/// Name of the implementation given by [`Solution::part_one`] and [`Solution::part_two`]
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Parsed puzzle input whose solution type has been erased
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Answer;
+
+    /// Solves a part with one of the variants of the solution, or `None` if there's no such variant
+    fn solve_variant(&self, variant: &str, part: Part) -> Option<Answer>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => S::part_one(&self.0).into(),
+            Part::Two => S::part_two(&self.0).into(),
+        }
+    }
+
+    fn solve_variant(&self, variant: &str, part: Part) -> Option<Answer> {
+        if variant == DEFAULT_VARIANT {
+            return Some(self.solve(part));
+        }
+
+        let variant = S::VARIANTS.iter().find(|v| v.name == variant)?;
+        let answer = match part {
+            Part::One => (variant.part_one)(&self.0).into(),
+            Part::Two => (variant.part_two)(&self.0).into(),
+        };
+        Some(answer)
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, AocError> {
+    Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+}
+
+// Type-erased entry point into a [`Visualize`] implementation
+type Animate = fn(&str, Part) -> Result<Frames, AocError>;
+
+fn animate_boxed<S: Visualize + 'static>(input: &str, part: Part) -> Result<Frames, AocError> {
+    S::frames(S::parse(input)?, part)
+}
+
+fn variant_names<S: Solution>() -> Vec<&'static str> {
+    let alternatives = S::VARIANTS.iter().map(|variant| variant.name);
+    std::iter::once(DEFAULT_VARIANT).chain(alternatives).collect()
+}
+
+/// A registered day: its metadata and a type-erased entry point into its [`Solution`]
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Parsed>, AocError>,
+    variants: fn() -> Vec<&'static str>,
+    animate: Option<Animate>,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution + 'static>() -> Puzzle {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            parse: parse_boxed::<S>,
+            variants: variant_names::<S>,
+            animate: None,
+        }
+    }
+
+    /// Same as [`Puzzle::of`], for a day whose simulation can be watched
+    pub const fn animated<S: Visualize + 'static>() -> Puzzle {
+        Puzzle { animate: Some(animate_boxed::<S>), ..Puzzle::of::<S>() }
+    }
+
+    /// Parses the input, returning a value that can answer both parts
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, AocError> {
+        (self.parse)(input)
+    }
+
+    /// Frames of the simulation of a part, or `None` if the day can't be watched
+    pub fn animate(&self, input: &str, part: Part) -> Option<Result<Frames, AocError>> {
+        self.animate.map(|animate| animate(input, part))
+    }
+
+    /// Names of the implementations of the day, starting with [`DEFAULT_VARIANT`]
+    pub fn variants(&self) -> Vec<&'static str> {
+        (self.variants)()
+    }
+
+    /// Checks that the day has a variant called `variant`
+    pub fn check_variant(&self, variant: &str) -> Result<(), String> {
+        let variants = self.variants();
+        if variants.contains(&variant) {
+            Ok(())
+        }
+        else {
+            Err(format!("day {} has no variant '{variant}' (available: {})", self.day, variants.join(", ")))
+        }
+    }
+}
+
+/// The solved days of one year
+pub struct Year {
+    pub year: u16,
+    pub puzzles: &'static [Puzzle],
+}
+
+/// Every year with solved days, ordered by year
+pub const YEARS: &[Year] = &[
+    Year { year: 2022, puzzles: crate::y2022::PUZZLES },
+];
+
+/// Year used when none is given: the most recent one
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("At least one year should be registered").year
+}
+
+/// Solved days of a year, ordered by day number (none if the year isn't registered)
+pub fn puzzles(year: u16) -> &'static [Puzzle] {
+    YEARS.iter().find(|y| y.year == year).map_or(&[], |y| y.puzzles)
+}
+
+/// Every solved day of every year
+pub fn all_puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|year| year.puzzles)
+}
+
+/// Finds the registered puzzle for a day
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    puzzles(year).iter().find(|puzzle| puzzle.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_unique_and_ordered() {
+        assert!(YEARS.windows(2).all(|pair| pair[0].year < pair[1].year));
+        for Year { year, puzzles } in YEARS {
+            assert!(puzzles.windows(2).all(|pair| pair[0].day < pair[1].day));
+            assert!(puzzles.iter().all(|puzzle| puzzle.year == *year && (1..=25).contains(&puzzle.day)));
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2022, 9).map(|puzzle| puzzle.title), Some("Rope Bridge"));
+        assert!(find(2022, 26).is_none());
+        assert!(find(1999, 9).is_none());
+        assert_eq!(latest_year(), 2022);
+    }
+
+    #[test]
+    fn test_variants() {
+        for puzzle in all_puzzles() {
+            let variants = puzzle.variants();
+            assert_eq!(variants[0], DEFAULT_VARIANT);
+            assert!(variants.iter().enumerate().all(|(idx, name)| !name.is_empty() && !variants[..idx].contains(name)), "day {}", puzzle.day);
+        }
+
+        let day3 = find(2022, 3).unwrap();
+        assert!(day3.check_variant("bitmask").is_ok());
+        assert_eq!(day3.check_variant("magic").unwrap_err(), "day 3 has no variant 'magic' (available: default, hashset, bitmask)");
+    }
+
+    // Every variant must agree with the default implementation on every example, recorded answers or not
+    #[test]
+    fn test_variants_agree_on_examples() {
+        for puzzle in all_puzzles() {
+            for example in crate::examples::load_all(puzzle.year, puzzle.day).unwrap() {
+                let parsed = puzzle.parse(&example.input).unwrap_or_else(|err| panic!("{} day {} ({}): {err}", puzzle.year, puzzle.day, example.name));
+                for variant in &puzzle.variants()[1..] {
+                    for part in Part::BOTH {
+                        assert_eq!(parsed.solve_variant(variant, part), Some(parsed.solve(part)), "{} day {} ({}), {variant}, part {part}", puzzle.year, puzzle.day, example.name);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,487 @@
+//! Running registered puzzles and presenting their answers.
+
+use std::{
+    any::Any,
+    cell::Cell,
+    env,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    process,
+    str::FromStr,
+    sync::{atomic::{AtomicUsize, Ordering}, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{bench::format_duration, input::InputSource, registry::{self, Puzzle}, Answer, AocError, Part};
+
+/// Answer of one part of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part: Part,
+    pub answer: Answer,
+    pub parse_time: Duration, // Time spent parsing the input (shared by both parts of a day)
+    pub solve_time: Duration,
+}
+
+/// How results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<OutputFormat, String> {
+        match format {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("unknown format '{format}', expected table, json or csv")),
+        }
+    }
+}
+
+/// Renders the results in the given format
+pub fn render(results: &[PartResult], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => render_table(results),
+        OutputFormat::Json => render_json(results),
+        OutputFormat::Csv => render_csv(results),
+    }
+}
+
+/// Parses the input once and solves the requested parts (both if `part` is `None`)
+pub fn solve(puzzle: &Puzzle, input: &str, part: Option<Part>) -> Result<Vec<PartResult>, AocError> {
+    solve_variant(puzzle, input, part, registry::DEFAULT_VARIANT)
+}
+
+/// Same as [`solve`], with one of the variants of the puzzle.
+///
+/// Panics if the puzzle has no such variant (see [`Puzzle::check_variant`])
+pub fn solve_variant(puzzle: &Puzzle, input: &str, part: Option<Part>, variant: &str) -> Result<Vec<PartResult>, AocError> {
+    let start = Instant::now();
+    let parsed = puzzle.parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let results = parts
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = parsed
+                .solve_variant(variant, part)
+                .unwrap_or_else(|| panic!("day {} has no variant '{variant}'", puzzle.day));
+
+            PartResult {
+                year: puzzle.year,
+                day: puzzle.day,
+                title: puzzle.title,
+                part,
+                answer,
+                parse_time,
+                solve_time: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(results)
+}
+
+// Message given to 'panic!', when it's a string (like the ones of 'todo!()' and 'expect')
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+thread_local! {
+    // Set while the current thread runs 'without_panic_messages'
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+// Installs, once and for all, a panic hook printing nothing on the silenced threads
+fn install_silencing_hook() {
+    static INSTALLED: OnceLock<()> = OnceLock::new();
+    INSTALLED.get_or_init(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.get() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+// Restores the previous state of the current thread when dropped, even while unwinding
+struct Unsilence(bool);
+
+impl Drop for Unsilence {
+    fn drop(&mut self) {
+        SILENCED.set(self.0);
+    }
+}
+
+/* Runs 'f' without printing the message of the panics that happen on the current thread. Other
+ * threads keep printing theirs. Unwritten solvers panic (with 'todo!()'), which is reported as a
+ * failure of their day instead */
+pub fn without_panic_messages<T>(f: impl FnOnce() -> T) -> T {
+    install_silencing_hook();
+    let _unsilence = Unsilence(SILENCED.replace(true));
+    f()
+}
+
+/* Solves several puzzles on a pool of threads (one per available core), reading the input of each
+ * puzzle with 'read_input'. A panicking solver only fails its own day, and its message is part of
+ * the error rather than printed.
+ *
+ * The results are in the same order as the puzzles, whichever finishes first */
+pub fn solve_parallel<F>(puzzles: &[&Puzzle], part: Option<Part>, read_input: F) -> Vec<Result<Vec<PartResult>, AocError>>
+where
+    F: Fn(&Puzzle) -> Result<String, AocError> + Sync,
+{
+    let next_puzzle = AtomicUsize::new(0); // Index of the next puzzle to hand to a worker
+    let results: Vec<Mutex<Option<_>>> = puzzles.iter().map(|_| Mutex::new(None)).collect();
+    let workers = thread::available_parallelism().map_or(1, |cores| cores.get()).min(puzzles.len());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| without_panic_messages(|| {
+                loop {
+                    let idx = next_puzzle.fetch_add(1, Ordering::Relaxed);
+                    let Some(puzzle) = puzzles.get(idx) else {
+                        break; // Every puzzle has been handed out
+                    };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| read_input(puzzle).and_then(|input| solve(puzzle, &input, part))))
+                        .unwrap_or_else(|payload| Err(AocError::invalid(puzzle.day, format!("panicked: {}", panic_message(&*payload)))));
+                    *results[idx].lock().unwrap() = Some(result);
+                }
+            }));
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().expect("Every puzzle should have been solved"))
+        .collect()
+}
+
+/* Renders the results as an aligned table.
+ *
+ * Multi-line answers (like the CRT image of day 10) continue on the following rows, with the other
+ * columns left blank */
+pub fn render_table(results: &[PartResult]) -> String {
+    let header = ("Day", "Title", "Part", "Answer");
+
+    let title_width = results.iter().map(|r| r.title.len()).chain([header.1.len()]).max().unwrap();
+    let answer_width = results
+        .iter()
+        .flat_map(|r| r.answer.to_string().lines().map(str::len).collect::<Vec<_>>())
+        .chain([header.3.len()])
+        .max()
+        .unwrap();
+
+    let mut table = String::new();
+    writeln!(table, "{:>3} | {:<title_width$} | {:>4} | {}", header.0, header.1, header.2, header.3).unwrap();
+    writeln!(table, "{}-+-{}-+-{}-+-{}", "-".repeat(3), "-".repeat(title_width), "-".repeat(4), "-".repeat(answer_width)).unwrap();
+
+    for result in results {
+        let answer = result.answer.to_string();
+        let mut lines = answer.lines();
+
+        let first_line = lines.next().unwrap_or("");
+        writeln!(table, "{:>3} | {:<title_width$} | {:>4} | {}", result.day, result.title, result.part, first_line).unwrap();
+        for line in lines {
+            writeln!(table, "{:>3} | {:<title_width$} | {:>4} | {}", "", "", "", line).unwrap();
+        }
+    }
+    table
+}
+
+/* Renders the results as a summary with one row per day: both answers, and the time spent parsing
+ * and solving them. Parts that weren't solved are shown as '-', and the days in 'failed' (given by
+ * number and title) as 'failed'.
+ *
+ * Like in 'render_table', multi-line answers continue on the following rows */
+pub fn render_summary(results: &[PartResult], failed: &[(u8, &str)]) -> String {
+    let header = ["Day", "Title", "Part 1", "Part 2", "Time"];
+
+    let mut rows: Vec<[String; 5]> = Vec::new();
+    for day_results in results.chunk_by(|a, b| a.day == b.day) {
+        let answer = |part: Part| {
+            day_results
+                .iter()
+                .find(|result| result.part == part)
+                .map_or("-".to_owned(), |result| result.answer.to_string())
+        };
+        let time = day_results[0].parse_time + day_results.iter().map(|result| result.solve_time).sum::<Duration>();
+
+        rows.push([
+            day_results[0].day.to_string(),
+            day_results[0].title.to_owned(),
+            answer(Part::One),
+            answer(Part::Two),
+            format_duration(time),
+        ]);
+    }
+    for &(day, title) in failed {
+        rows.push([day.to_string(), title.to_owned(), "failed".to_owned(), "failed".to_owned(), "-".to_owned()]);
+    }
+    rows.sort_by_key(|row| row[0].parse::<u8>().unwrap_or(0)); // Stable, so multi-line rows keep their order
+
+    // Each column is as wide as its longest line
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .flat_map(|row| row[column].lines().map(|line| line.chars().count()).collect::<Vec<_>>())
+                .chain([header[column].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_line = |cells: [&str; 5]| {
+        let line = format!(
+            "{:>w0$} | {:<w1$} | {:<w2$} | {:<w3$} | {:>w4$}",
+            cells[0], cells[1], cells[2], cells[3], cells[4],
+            w0 = widths[0], w1 = widths[1], w2 = widths[2], w3 = widths[3], w4 = widths[4],
+        );
+        line.trim_end().to_owned()
+    };
+
+    let mut table = String::new();
+    writeln!(table, "{}", format_line(header)).unwrap();
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    writeln!(table, "{}", separator.join("-+-")).unwrap();
+
+    for row in &rows {
+        let lines: Vec<Vec<&str>> = row.iter().map(|cell| cell.lines().collect()).collect();
+        let height = lines.iter().map(Vec::len).max().unwrap_or(1);
+
+        for line_idx in 0..height {
+            let cell = |column: usize| lines[column].get(line_idx).copied().unwrap_or("");
+            writeln!(table, "{}", format_line([cell(0), cell(1), cell(2), cell(3), cell(4)])).unwrap();
+        }
+    }
+    table
+}
+
+// Escapes a string for a JSON document
+pub(crate) fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// Answer as a JSON value: a number, a string, or an array of rows for bitmaps
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(value) => value.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::Bitmap(rows) => format!("[{}]", rows.lines().map(json_string).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+/// Renders the results as a JSON array, with one object per part and times in nanoseconds
+pub fn render_json(results: &[PartResult]) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|result| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"title\": {}, \"part\": {}, \"type\": \"{}\", \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}}}",
+                result.year,
+                result.day,
+                json_string(result.title),
+                result.part,
+                result.answer.kind(),
+                json_answer(&result.answer),
+                result.parse_time.as_nanos(),
+                result.solve_time.as_nanos(),
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        return "[]\n".to_owned();
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+// Quotes a CSV field when it contains a separator, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Renders the results as CSV, with times in nanoseconds
+pub fn render_csv(results: &[PartResult]) -> String {
+    let mut csv = "year,day,title,part,type,answer,parse_ns,solve_ns\n".to_owned();
+    for result in results {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            csv_field(result.title),
+            result.part,
+            result.answer.kind(),
+            csv_field(&result.answer.to_string()),
+            result.parse_time.as_nanos(),
+            result.solve_time.as_nanos(),
+        ).unwrap();
+    }
+    csv
+}
+
+/* Entry point of the single-day binaries: solves both parts of 'day' of 'year' and prints them as
+ * a table.
+ *
+ * The first argument, if any, is the input to use (a path, or '-' for stdin) */
+pub fn main_for(year: u16, day: u8) {
+    let puzzle = registry::find(year, day).expect("The day should be registered");
+    let source = env::args().nth(1).map_or(InputSource::Default, |arg| InputSource::from_arg(&arg));
+
+    match source.read(year, day).and_then(|input| solve(puzzle, &input, None)) {
+        Ok(results) => print!("{}", render_table(&results)),
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, title: &'static str, part: Part, answer: Answer) -> PartResult {
+        PartResult { year: 2022, day, title, part, answer, parse_time: Duration::from_nanos(1500), solve_time: Duration::from_nanos(20) }
+    }
+
+    fn example_results() -> [PartResult; 3] {
+        [
+            result(1, "Calorie Counting", Part::One, Answer::Integer(24000)),
+            result(5, "Supply Stacks", Part::One, Answer::Text("CMZ".to_owned())),
+            result(10, "Cathode-Ray Tube", Part::Two, Answer::Bitmap("##..\n..##".to_owned())),
+        ]
+    }
+
+    #[test]
+    fn test_render_table() {
+        let results = [&example_results()[0], &example_results()[2]].map(Clone::clone);
+
+        let expected = "\
+Day | Title            | Part | Answer
+----+------------------+------+-------
+  1 | Calorie Counting |    1 | 24000
+ 10 | Cathode-Ray Tube |    2 | ##..
+    |                  |      | ..##
+";
+        assert_eq!(render_table(&results), expected);
+    }
+
+    #[test]
+    fn test_render_summary() {
+        let mut results = example_results().to_vec();
+        results.insert(1, result(1, "Calorie Counting", Part::Two, Answer::Integer(45000)));
+
+        let expected = "\
+Day | Title            | Part 1 | Part 2 |   Time
+----+------------------+--------+--------+-------
+  1 | Calorie Counting | 24000  | 45000  | 1.5 µs
+  5 | Supply Stacks    | CMZ    | -      | 1.5 µs
+  7 | No Space         | failed | failed |      -
+ 10 | Cathode-Ray Tube | -      | ##..   | 1.5 µs
+    |                  |        | ..##   |
+";
+        assert_eq!(render_summary(&results, &[(7, "No Space")]), expected);
+    }
+
+    #[test]
+    fn test_solve_parallel() {
+        let puzzles: Vec<&Puzzle> = registry::all_puzzles().collect();
+        let results = solve_parallel(&puzzles, None, |puzzle| {
+            let examples = crate::examples::load_all(puzzle.year, puzzle.day).map_err(|err| AocError::invalid(puzzle.day, err))?;
+            Ok(examples[0].input.clone())
+        });
+
+        assert_eq!(results.len(), puzzles.len());
+        for (puzzle, result) in puzzles.iter().zip(results) {
+            let result = result.unwrap();
+            assert_eq!(result.iter().map(|r| (r.day, r.part)).collect::<Vec<_>>(), [(puzzle.day, Part::One), (puzzle.day, Part::Two)]);
+        }
+
+        let results = solve_parallel(&puzzles[..1], Some(Part::Two), |puzzle| Err(AocError::invalid(puzzle.day, "no input")));
+        assert_eq!(results[0].as_ref().unwrap_err().to_string(), "day 1: no input");
+
+        // A panic (like the 'todo!()' of a new day) only fails its day
+        let results = solve_parallel(&puzzles[..2], None, |puzzle| match puzzle.day {
+            1 => todo!("Day 1"),
+            _ => Ok(crate::examples::load(puzzle.year, puzzle.day, "example").unwrap().input),
+        });
+        assert_eq!(results[0].as_ref().unwrap_err().to_string(), "day 1: panicked: not yet implemented: Day 1");
+        assert!(results[1].is_ok());
+    }
+
+    // Silencing only lasts for the call, even when it panics, and only concerns its thread
+    #[test]
+    fn test_without_panic_messages() {
+        let result = panic::catch_unwind(|| without_panic_messages(|| {
+            assert!(SILENCED.get());
+            assert!(!thread::spawn(|| SILENCED.get()).join().unwrap());
+            without_panic_messages(|| ());
+            assert!(SILENCED.get());
+            panic!("Silenced");
+        }));
+        assert_eq!(panic_message(&*result.unwrap_err()), "Silenced");
+        assert!(!SILENCED.get());
+    }
+
+    #[test]
+    fn test_render_json() {
+        let expected = r###"[
+  {"year": 2022, "day": 1, "title": "Calorie Counting", "part": 1, "type": "integer", "answer": 24000, "parse_ns": 1500, "solve_ns": 20},
+  {"year": 2022, "day": 5, "title": "Supply Stacks", "part": 1, "type": "string", "answer": "CMZ", "parse_ns": 1500, "solve_ns": 20},
+  {"year": 2022, "day": 10, "title": "Cathode-Ray Tube", "part": 2, "type": "bitmap", "answer": ["##..", "..##"], "parse_ns": 1500, "solve_ns": 20}
+]
+"###;
+        assert_eq!(render_json(&example_results()), expected);
+        assert_eq!(json_string("a\"b\\c\td"), r#""a\"b\\c\u0009d""#);
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let expected = "\
+year,day,title,part,type,answer,parse_ns,solve_ns
+2022,1,Calorie Counting,1,integer,24000,1500,20
+2022,5,Supply Stacks,1,string,CMZ,1500,20
+2022,10,Cathode-Ray Tube,2,bitmap,\"##..\n..##\",1500,20
+";
+        assert_eq!(render_csv(&example_results()), expected);
+    }
+}
@@ -0,0 +1,220 @@
+//! Generating the files of a new day.
+//!
+//! A new day gets a solver module following the shared conventions, an example file waiting for
+//! the puzzle's example and a test that fails until the solver is written. The module is then
+//! declared and registered in the module of its year (`src/y<year>/mod.rs`). The first day of a
+//! year also creates that module, and registers it in `lib.rs` and `registry.rs`.
+//!
+//! New days are run through `aoc run`; only the days of 2022 have binaries of their own.
+
+use std::{fs, ops::RangeInclusive, path::{Path, PathBuf}};
+
+const DAYS: RangeInclusive<u16> = 1..=25;
+const YEARS: RangeInclusive<u16> = 2015..=2099;
+
+/* Solver module of a new day, with a failing test on the (still empty) example. The title is written
+ * as a Rust string literal, escaped as needed */
+pub fn solver_template(year: u16, day: u8, title: &str) -> String {
+    format!(r#"use crate::{{AocError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title:?};
+
+    type Input = Vec<String>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Vec<String>, AocError> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    // Both parts panic until they're written: 'aoc run --all' reports the day as failed meanwhile
+    fn part_one(_input: &Vec<String>) -> i32 {{
+        todo!("Day {day}, part one")
+    }}
+
+    fn part_two(_input: &Vec<String>) -> i32 {{
+        todo!("Day {day}, part two")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_part_one() {{
+        let example = examples::load({year}, {day}, "example").unwrap();
+        let input = Day{day}::parse(&example.input).unwrap();
+
+        assert_eq!(Day{day}::part_one(&input), 0); // Replace with the answer of the example
+    }}
+}}
+"#)
+}
+
+// Module of a new year, holding its first day
+pub fn year_template(year: u16, day: u8) -> String {
+    format!(r#"//! Solutions of Advent of Code {year}.
+
+use crate::registry::Puzzle;
+
+pub mod day{day:02};
+
+/// Every solved day of {year}, ordered by day number
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::of::<day{day:02}::Day{day}>(),
+];
+"#)
+}
+
+// Example file without answers nor input yet (see the 'examples' module for the format)
+pub const EXAMPLE_TEMPLATE: &str = "\
+# Expected answers of the example, like 'part1 = 24000'
+---
+";
+
+/* Inserts the line of 'key' (a day or a year) in the block of lines built by one of 'forms' for the
+ * keys in 'keys', keeping the block ordered. The line is built by the first form. The block must
+ * exist already and can't contain 'key' */
+fn insert_line(source: &str, key: u16, keys: RangeInclusive<u16>, forms: &[fn(u16) -> String]) -> Result<String, String> {
+    let line_for = forms[0];
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let block: Vec<(usize, u16)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| keys.clone().find(|&n| forms.iter().any(|form| *line == form(n))).map(|n| (idx, n)))
+        .collect();
+
+    let (first_idx, _) = *block.first().ok_or_else(|| format!("no line like '{}' was found", line_for(*keys.start()).trim()))?;
+    if block.iter().any(|&(_, n)| n == key) {
+        return Err(format!("'{}' is already there", line_for(key).trim()));
+    }
+
+    let position = block
+        .iter()
+        .filter(|&&(_, n)| n < key)
+        .map(|&(idx, _)| idx + 1)
+        .next_back()
+        .unwrap_or(first_idx);
+    lines.insert(position, line_for(key));
+
+    let mut updated = lines.join("\n");
+    if source.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// Declares and registers the module of `day` in the contents of the module of its year
+pub fn register_day(year_rs: &str, day: u8) -> Result<String, String> {
+    let year_rs = insert_line(year_rs, day.into(), DAYS, &[|n| format!("pub mod day{n:02};")])?;
+
+    // Days that can be watched are registered with 'Puzzle::animated'
+    let of = |n| format!("    Puzzle::of::<day{n:02}::Day{n}>(),");
+    let animated = |n| format!("    Puzzle::animated::<day{n:02}::Day{n}>(),");
+    insert_line(&year_rs, day.into(), DAYS, &[of, animated])
+}
+
+/// Declares the module of `year` in the contents of `lib.rs`
+pub fn register_year_module(lib_rs: &str, year: u16) -> Result<String, String> {
+    insert_line(lib_rs, year, YEARS, &[|n| format!("pub mod y{n};")])
+}
+
+/// Registers the days of `year` in the contents of `registry.rs`
+pub fn register_year(registry_rs: &str, year: u16) -> Result<String, String> {
+    insert_line(registry_rs, year, YEARS, &[|n| format!("    Year {{ year: {n}, puzzles: crate::y{n}::PUZZLES }},")])
+}
+
+/// Creates every file of a new day under the crate at `root`, returning the paths written
+pub fn create_day(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let solver_path = year_dir.join(format!("day{day:02}.rs"));
+    if solver_path.exists() {
+        return Err(format!("{} already exists", solver_path.display()));
+    }
+
+    let read = |path: &Path| fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()));
+    let year_path = year_dir.join("mod.rs");
+    let mut files = vec![(solver_path, solver_template(year, day, title))];
+
+    // Updating the sources in memory first, so nothing is written if one of them can't be updated
+    if year_path.exists() {
+        let year_rs = register_day(&read(&year_path)?, day).map_err(|err| format!("{}: {err}", year_path.display()))?;
+        files.push((year_path, year_rs));
+    }
+    else {
+        let lib_path = root.join("src").join("lib.rs");
+        let registry_path = root.join("src").join("registry.rs");
+
+        let lib_rs = register_year_module(&read(&lib_path)?, year).map_err(|err| format!("{}: {err}", lib_path.display()))?;
+        let registry_rs = register_year(&read(&registry_path)?, year).map_err(|err| format!("{}: {err}", registry_path.display()))?;
+        files.extend([(year_path, year_template(year, day)), (lib_path, lib_rs), (registry_path, registry_rs)]);
+    }
+
+    let example_path = root.join("examples").join(year.to_string()).join(format!("day{day}")).join("example.txt");
+    if !example_path.exists() {
+        files.push((example_path, EXAMPLE_TEMPLATE.to_owned()));
+    }
+
+    for (path, contents) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| format!("couldn't create {}: {err}", dir.display()))?;
+        }
+        fs::write(path, contents).map_err(|err| format!("couldn't write {}: {err}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_day() {
+        let year_rs = "pub mod day01;\npub mod day09;\npub mod day10;\n\nconst PUZZLES: &[Puzzle] = &[\n    Puzzle::of::<day01::Day1>(),\n    Puzzle::of::<day09::Day9>(),\n    Puzzle::of::<day10::Day10>(),\n];\n";
+
+        assert_eq!(
+            register_day(year_rs, 7).unwrap(),
+            "pub mod day01;\npub mod day07;\npub mod day09;\npub mod day10;\n\nconst PUZZLES: &[Puzzle] = &[\n    Puzzle::of::<day01::Day1>(),\n    Puzzle::of::<day07::Day7>(),\n    Puzzle::of::<day09::Day9>(),\n    Puzzle::of::<day10::Day10>(),\n];\n",
+        );
+        assert!(register_day(year_rs, 12).unwrap().contains("pub mod day10;\npub mod day12;\n"));
+        assert!(register_day(year_rs, 9).is_err());
+
+        // The last day registered can be an animated one
+        let year_rs = "pub mod day06;\npub mod day11;\n\nconst PUZZLES: &[Puzzle] = &[\n    Puzzle::of::<day06::Day6>(),\n    Puzzle::animated::<day11::Day11>(),\n];\n";
+        assert!(register_day(year_rs, 12).unwrap().contains("    Puzzle::animated::<day11::Day11>(),\n    Puzzle::of::<day12::Day12>(),\n];"));
+        assert!(register_day(year_rs, 11).is_err());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib_rs = "pub mod y2022;\n\npub mod answers;\n";
+        assert_eq!(register_year_module(lib_rs, 2021).unwrap(), "pub mod y2021;\npub mod y2022;\n\npub mod answers;\n");
+
+        let registry_rs = "const YEARS: &[Year] = &[\n    Year { year: 2022, puzzles: crate::y2022::PUZZLES },\n];\n";
+        assert_eq!(
+            register_year(registry_rs, 2023).unwrap(),
+            "const YEARS: &[Year] = &[\n    Year { year: 2022, puzzles: crate::y2022::PUZZLES },\n    Year { year: 2023, puzzles: crate::y2023::PUZZLES },\n];\n",
+        );
+    }
+
+    // The generated files must be accepted by the real sources
+    #[test]
+    fn test_templates_fit_the_crate() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let year_rs = fs::read_to_string(src.join("y2022").join("mod.rs")).unwrap();
+
+        assert!(register_day(&year_rs, 25).unwrap().contains("    Puzzle::of::<day25::Day25>(),\n];"));
+        assert!(register_day(&year_template(2023, 1), 2).is_ok());
+        assert!(register_year_module(&fs::read_to_string(src.join("lib.rs")).unwrap(), 2023).is_ok());
+        assert!(register_year(&fs::read_to_string(src.join("registry.rs")).unwrap(), 2023).is_ok());
+        assert!(solver_template(2022, 25, "Full of Hot Air").contains("const TITLE: &'static str = \"Full of Hot Air\";"));
+        assert!(solver_template(2022, 25, r#"The "Hot" Air \ Balloon"#).contains(r#"const TITLE: &'static str = "The \"Hot\" Air \\ Balloon";"#));
+    }
+}
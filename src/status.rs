@@ -0,0 +1,296 @@
+//! Where an event stands: which days and parts are solved, and whether their answers are verified.
+//!
+//! Each registered day is solved once on its input. A part counts as solved when its solver returns,
+//! as missing when it panics with the `todo!()` of a new day (and as failed on any other panic), and
+//! as verified when its answer matches the recorded one.
+
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{self, Answers, Verdict},
+    bench::format_duration,
+    input::{self, InputSource},
+    registry::{self, Puzzle},
+    runner::panic_message,
+    Part,
+};
+
+/// State of one part of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    /// No solver, or one that isn't written yet
+    Missing,
+    /// Solver that couldn't be run, for lack of an input
+    Untested,
+    /// The input couldn't be parsed, or the solver panicked
+    Failed,
+    /// Solved, but the answer isn't recorded
+    Unverified,
+    Verified,
+    /// Solved with an answer other than the recorded one
+    Wrong,
+}
+
+impl PartStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PartStatus::Missing => "missing",
+            PartStatus::Untested => "untested",
+            PartStatus::Failed => "failed",
+            PartStatus::Unverified => "unverified",
+            PartStatus::Verified => "verified",
+            PartStatus::Wrong => "wrong",
+        }
+    }
+
+    // Mark of the part in the calendar
+    fn symbol(&self) -> char {
+        match self {
+            PartStatus::Missing => '.',
+            PartStatus::Untested => '?',
+            PartStatus::Failed | PartStatus::Wrong => '!',
+            PartStatus::Unverified => '+',
+            PartStatus::Verified => '*',
+        }
+    }
+}
+
+/// State of one day of an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub title: Option<&'static str>, // Only known for the days with a solver
+    pub input: bool,
+    pub parts: [PartStatus; 2],
+    pub time: Option<Duration>, // Time spent parsing and solving the parts that have a solver
+}
+
+impl DayStatus {
+    /// Status of a day without a solver
+    pub fn missing(day: u8, input: bool) -> DayStatus {
+        DayStatus { day, title: None, input, parts: [PartStatus::Missing; 2], time: None }
+    }
+}
+
+// Status of a part whose parser or solver panicked: only 'todo!()' means it isn't written yet
+fn panicked(payload: &(dyn Any + Send)) -> PartStatus {
+    match panic_message(payload).contains("not yet implemented") {
+        true => PartStatus::Missing,
+        false => PartStatus::Failed,
+    }
+}
+
+/// Solves a day on its input (if there's one) and compares its answers with the recorded ones
+pub fn check(puzzle: &Puzzle, input: Option<&str>, answers: &Answers) -> DayStatus {
+    let mut status = DayStatus {
+        day: puzzle.day,
+        title: Some(puzzle.title),
+        input: input.is_some(),
+        parts: [PartStatus::Untested; 2],
+        time: None,
+    };
+    let Some(input) = input else {
+        return status;
+    };
+
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(|| puzzle.parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(_)) => {
+            status.parts = [PartStatus::Failed; 2];
+            return status;
+        },
+        Err(payload) => {
+            status.parts = [panicked(&*payload); 2];
+            return status;
+        },
+    };
+
+    let mut time = start.elapsed();
+    for (idx, part) in Part::BOTH.into_iter().enumerate() {
+        let start = Instant::now();
+        status.parts[idx] = match panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part))) {
+            Err(payload) => panicked(&*payload),
+            Ok(answer) => {
+                time += start.elapsed();
+                match answers.verify(puzzle.day, part, &answer) {
+                    Verdict::Correct => PartStatus::Verified,
+                    Verdict::Wrong { .. } => PartStatus::Wrong,
+                    Verdict::Unrecorded => PartStatus::Unverified,
+                }
+            },
+        };
+    }
+    status.time = Some(time);
+    status
+}
+
+/// Status of every day (1 to 25) of a year, with the inputs and answers files of the crate
+pub fn year_status(year: u16) -> Result<Vec<DayStatus>, String> {
+    let answers = Answers::load(&answers::default_path(year))?;
+
+    let statuses = (1..=25)
+        .map(|day| {
+            let input = InputSource::Default.read(year, day).ok();
+            match registry::find(year, day) {
+                Some(puzzle) => check(puzzle, input.as_deref(), &answers),
+                None => DayStatus::missing(day, input::default_path(year, day).exists()),
+            }
+        })
+        .collect();
+    Ok(statuses)
+}
+
+/// Number of verified parts, like the stars of the website
+pub fn stars(statuses: &[DayStatus]) -> usize {
+    statuses.iter().flat_map(|status| status.parts).filter(|&part| part == PartStatus::Verified).count()
+}
+
+/// Renders the statuses as an aligned table, with one row per day
+pub fn render_table(statuses: &[DayStatus]) -> String {
+    let header = ["Day", "Title", "Part 1", "Part 2", "Input", "Time"];
+    let title_width = statuses.iter().filter_map(|s| s.title).map(str::len).chain([header[1].len()]).max().unwrap();
+    let part_width = "unverified".len();
+
+    let format_row = |cells: [&str; 6]| {
+        let line = format!(
+            "{:>3} | {:<title_width$} | {:<part_width$} | {:<part_width$} | {:<5} | {:>9}",
+            cells[0], cells[1], cells[2], cells[3], cells[4], cells[5],
+        );
+        line.trim_end().to_owned()
+    };
+
+    let mut table = String::new();
+    writeln!(table, "{}", format_row(header)).unwrap();
+    let separator = [3, title_width, part_width, part_width, 5, 9].map(|width| "-".repeat(width));
+    writeln!(table, "{}", separator.join("-+-")).unwrap();
+
+    for status in statuses {
+        let time = status.time.map_or("-".to_owned(), format_duration);
+        let row = format_row([
+            &status.day.to_string(),
+            status.title.unwrap_or(""),
+            status.parts[0].name(),
+            status.parts[1].name(),
+            if status.input { "yes" } else { "no" },
+            &time,
+        ]);
+        writeln!(table, "{row}").unwrap();
+    }
+    writeln!(table, "{} of {} stars", stars(statuses), 2 * statuses.len()).unwrap();
+    table
+}
+
+/* Renders the statuses as a calendar of 5 rows of 5 days, where each day shows the mark of both of
+ * its parts (see the legend under the calendar) */
+pub fn render_calendar(statuses: &[DayStatus]) -> String {
+    let mut calendar = String::new();
+    for week in statuses.chunks(5) {
+        let cells: Vec<String> = week
+            .iter()
+            .map(|status| format!("{:>2} {}{}", status.day, status.parts[0].symbol(), status.parts[1].symbol()))
+            .collect();
+        writeln!(calendar, "{}", cells.join("   ")).unwrap();
+    }
+    writeln!(calendar, "\n* verified   + unverified   ? no input   ! wrong or failing   . missing").unwrap();
+    writeln!(calendar, "{} of {} stars", stars(statuses), 2 * statuses.len()).unwrap();
+    calendar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, runner::without_panic_messages, Answer, AocError, Solution};
+
+    // Day whose second part isn't written yet
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 7;
+        const TITLE: &'static str = "Unfinished";
+
+        type Input = ();
+        type PartOne = i32;
+        type PartTwo = i32;
+
+        fn parse(_input: &str) -> Result<(), AocError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &()) -> i32 {
+            1
+        }
+
+        fn part_two(_input: &()) -> i32 {
+            todo!()
+        }
+    }
+
+    // Day whose first part is written, but wrong
+    struct Broken;
+
+    impl Solution for Broken {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 8;
+        const TITLE: &'static str = "Broken";
+
+        type Input = Vec<i32>;
+        type PartOne = i32;
+        type PartTwo = i32;
+
+        fn parse(_input: &str) -> Result<Vec<i32>, AocError> {
+            Ok(Vec::new())
+        }
+
+        fn part_one(input: &Vec<i32>) -> i32 {
+            input[0]
+        }
+
+        fn part_two(_input: &Vec<i32>) -> i32 {
+            todo!("Part two")
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let example = examples::load(2022, 1, "example").unwrap();
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, Answer::Integer(24000));
+        answers.insert(1, Part::Two, Answer::Integer(1));
+
+        let status = check(puzzle, Some(&example.input), &answers);
+        assert_eq!((status.title, status.input), (Some("Calorie Counting"), true));
+        assert_eq!(status.parts, [PartStatus::Verified, PartStatus::Wrong]);
+        assert!(status.time.is_some());
+
+        assert_eq!(check(puzzle, None, &answers).parts, [PartStatus::Untested; 2]);
+        assert_eq!(check(puzzle, Some("1\nx\n"), &answers).parts, [PartStatus::Failed; 2]);
+
+        let unfinished = without_panic_messages(|| check(&Puzzle::of::<Unfinished>(), Some(""), &Answers::default()));
+        assert_eq!(unfinished.parts, [PartStatus::Unverified, PartStatus::Missing]);
+        let broken = without_panic_messages(|| check(&Puzzle::of::<Broken>(), Some(""), &Answers::default()));
+        assert_eq!(broken.parts, [PartStatus::Failed, PartStatus::Missing]);
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        let mut statuses: Vec<DayStatus> = (1..=25).map(|day| DayStatus::missing(day, false)).collect();
+        statuses[0].parts = [PartStatus::Verified; 2];
+        statuses[1].parts = [PartStatus::Verified, PartStatus::Unverified];
+        statuses[5].parts = [PartStatus::Untested; 2];
+
+        let calendar = render_calendar(&statuses);
+        let lines: Vec<&str> = calendar.lines().collect();
+        assert_eq!(lines[0], " 1 **    2 *+    3 ..    4 ..    5 ..");
+        assert_eq!(lines[1], " 6 ??    7 ..    8 ..    9 ..   10 ..");
+        assert_eq!(lines.len(), 8);
+        assert!(calendar.ends_with("3 of 50 stars\n"));
+    }
+}
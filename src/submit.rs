@@ -0,0 +1,280 @@
+//! Submitting answers to the website.
+//!
+//! Accepted answers are recorded in the answers file of the year (see [`crate::answers`]), and
+//! rejected ones in `answers/<year>-rejected.csv`, so a value known to be wrong is never sent twice.
+//! Rejections that came with a hint also rule out the values beyond them: once 100 is too high,
+//! so is 120.
+
+use std::{fmt::Write, fs, io, path::{Path, PathBuf}};
+
+use crate::{
+    answers::Answers,
+    client::{Client, Submitted},
+    files::{crate_path, read_optional},
+    Answer, Part,
+};
+
+/// Hint given by the website with a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Hint {
+    fn name(&self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low",
+        }
+    }
+}
+
+/// Answer rejected by the website
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+/// Every rejected answer of a year
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rejections {
+    rejections: Vec<Rejection>,
+}
+
+const CSV_HEADER: &str = "day,part,hint,answer";
+
+impl Rejections {
+    pub fn insert(&mut self, rejection: Rejection) {
+        self.rejections.push(rejection);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Rejection> {
+        self.rejections.iter()
+    }
+
+    /// Checks that an answer isn't known to be wrong, explaining why it is otherwise
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<(), String> {
+        for rejection in self.rejections.iter().filter(|r| r.day == day && r.part == part) {
+            if rejection.answer == answer {
+                return Err(format!("{answer} was already rejected"));
+            }
+
+            let (Ok(value), Ok(rejected)) = (answer.parse::<i64>(), rejection.answer.parse::<i64>()) else {
+                continue;
+            };
+            match rejection.hint {
+                Some(Hint::TooHigh) if value > rejected => return Err(format!("{answer} is higher than {rejected}, which is too high")),
+                Some(Hint::TooLow) if value < rejected => return Err(format!("{answer} is lower than {rejected}, which is too low")),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    // The answer comes last, so it can hold commas
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for Rejection { day, part, answer, hint } in &self.rejections {
+            writeln!(csv, "{day},{part},{},{answer}", hint.map_or("", |hint| hint.name())).unwrap();
+        }
+        csv
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Rejections, String> {
+        let mut lines = csv.lines().enumerate();
+        if lines.next().map(|(_, header)| header) != Some(CSV_HEADER) {
+            return Err(format!("expected the header '{CSV_HEADER}'"));
+        }
+
+        let rejections = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_idx, line)| {
+                let error = || format!("line {}: invalid rejection '{line}'", line_idx + 1);
+                let [day, part, hint, answer] = line.splitn(4, ',').collect::<Vec<_>>()[..] else {
+                    return Err(error());
+                };
+                let hint = match hint {
+                    "" => None,
+                    "too high" => Some(Hint::TooHigh),
+                    "too low" => Some(Hint::TooLow),
+                    _ => return Err(error()),
+                };
+
+                Ok(Rejection {
+                    day: day.parse().map_err(|_| error())?,
+                    part: part.parse().map_err(|_| error())?,
+                    answer: answer.to_owned(),
+                    hint,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Rejections { rejections })
+    }
+
+    /// Loads the rejected answers; a missing file means that none were rejected yet
+    pub fn load(path: &Path) -> Result<Rejections, String> {
+        match read_optional(path)? {
+            Some(csv) => Rejections::from_csv(&csv).map_err(|err| format!("{}: {err}", path.display())),
+            None => Ok(Rejections::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_csv())
+    }
+}
+
+// Path of the rejected answers of a year, next to its answers file
+pub fn default_rejections_path(year: u16) -> PathBuf {
+    crate_path("answers")
+        .join(format!("{year}-rejected.csv"))
+}
+
+/// Answer as typed on the website; bitmaps have to be read by a human first
+pub fn answer_text(answer: &Answer) -> Result<String, String> {
+    match answer {
+        Answer::Integer(value) => Ok(value.to_string()),
+        Answer::Text(text) => Ok(text.clone()),
+        Answer::Bitmap(_) => Err("the answer is an image: read its letters and submit them on the website".to_owned()),
+    }
+}
+
+/* Submits the answer of a part, unless it's already known to be right or wrong, and records the
+ * verdict of the website in 'answers' or 'rejections'. Returns 'None' if the answer was recorded as
+ * accepted already */
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    answers: &mut Answers,
+    rejections: &mut Rejections,
+) -> Result<Option<Submitted>, String> {
+    let text = answer_text(answer)?;
+    match answers.get(day, part) {
+        Some(accepted) if accepted == answer => return Ok(None),
+        Some(accepted) => return Err(format!("{text} differs from the accepted answer, {accepted}")),
+        None => rejections.check(day, part, &text)?,
+    }
+
+    let submitted = client.submit_answer(year, day, part, &text).map_err(|err| err.to_string())?;
+    let rejection = |hint| Rejection { day, part, answer: text.clone(), hint };
+    match submitted {
+        Submitted::Correct => answers.insert(day, part, answer.clone()),
+        Submitted::TooHigh => rejections.insert(rejection(Some(Hint::TooHigh))),
+        Submitted::TooLow => rejections.insert(rejection(Some(Hint::TooLow))),
+        Submitted::Wrong => rejections.insert(rejection(None)),
+        Submitted::Wait(_) | Submitted::AlreadySolved => (),
+    }
+    Ok(Some(submitted))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::client::{self, ClientConfig};
+
+    fn rejection(answer: &str, hint: Option<Hint>) -> Rejection {
+        Rejection { day: 1, part: Part::Two, answer: answer.to_owned(), hint }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut rejections = Rejections::default();
+        rejections.insert(rejection("100", Some(Hint::TooHigh)));
+        rejections.insert(rejection("20", Some(Hint::TooLow)));
+        rejections.insert(rejection("50", None));
+
+        assert_eq!(rejections.check(1, Part::Two, "50"), Err("50 was already rejected".to_owned()));
+        assert_eq!(rejections.check(1, Part::Two, "120"), Err("120 is higher than 100, which is too high".to_owned()));
+        assert_eq!(rejections.check(1, Part::Two, "7"), Err("7 is lower than 20, which is too low".to_owned()));
+        assert!(rejections.check(1, Part::Two, "60").is_ok());
+        assert!(rejections.check(1, Part::One, "50").is_ok());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut rejections = Rejections::default();
+        rejections.insert(rejection("100", Some(Hint::TooHigh)));
+        rejections.insert(rejection("A,B", None));
+
+        let csv = rejections.to_csv();
+        assert_eq!(csv, "day,part,hint,answer\n1,2,too high,100\n1,2,,A,B\n");
+        assert_eq!(Rejections::from_csv(&csv).unwrap(), rejections);
+        assert!(Rejections::from_csv("day,part,hint,answer\n1,2,higher,100\n").is_err());
+    }
+
+    // Answers known to be right or wrong are never sent (the client has no server to reach)
+    #[test]
+    fn test_known_answers_are_not_submitted() {
+        let client = Client::new(ClientConfig {
+            base_url: "http://127.0.0.1:9".to_owned(),
+            session: "abc123".to_owned(),
+            min_interval: Duration::ZERO,
+            throttle_path: std::env::temp_dir().join(format!("aoc-2022-{}-submit-known", std::process::id())),
+            clock: std::time::SystemTime::now,
+        });
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, Answer::Integer(24000));
+        let mut rejections = Rejections::default();
+        rejections.insert(rejection("100", Some(Hint::TooHigh)));
+
+        let mut submit = |part, answer| submit(&client, 2022, 1, part, &answer, &mut answers, &mut rejections);
+        assert_eq!(submit(Part::One, Answer::Integer(24000)), Ok(None));
+        assert_eq!(submit(Part::One, Answer::Integer(1)), Err("1 differs from the accepted answer, 24000".to_owned()));
+        assert_eq!(submit(Part::Two, Answer::Integer(101)), Err("101 is higher than 100, which is too high".to_owned()));
+        assert!(submit(Part::Two, Answer::Bitmap("#.\n.#".to_owned())).is_err());
+    }
+
+    // Verdicts of the website end up in the answers and rejections files
+    #[test]
+    fn test_verdicts_are_recorded() {
+        let dir = client::tests::temp_dir("submit-verdicts");
+        let (base_url, server) = client::tests::stub_server(vec![
+            (200, "<article><p>That's the right answer!  You are one gold star closer.</p></article>"),
+            (200, "<article><p>That's not the right answer; your answer is too high.</p></article>"),
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's not the right answer.  If you're stuck...</p></article>"),
+        ]);
+        let client = client::tests::test_client(base_url, &dir, Duration::ZERO);
+        let (answers_path, rejections_path) = (dir.join("2022.toml"), dir.join("2022-rejected.csv"));
+
+        let mut answers = Answers::default();
+        let mut rejections = Rejections::default();
+        let mut submit = |day, part, answer| {
+            let submitted = submit(&client, 2022, day, part, &Answer::Integer(answer), &mut answers, &mut rejections);
+            answers.save(&answers_path).unwrap();
+            rejections.save(&rejections_path).unwrap();
+            submitted
+        };
+        assert_eq!(submit(1, Part::One, 24000), Ok(Some(Submitted::Correct)));
+        assert_eq!(submit(1, Part::Two, 100), Ok(Some(Submitted::TooHigh)));
+        assert_eq!(submit(1, Part::Two, 20), Ok(Some(Submitted::TooLow)));
+        assert_eq!(submit(1, Part::Two, 50), Ok(Some(Submitted::Wrong)));
+        server.join().unwrap();
+
+        // Nothing is sent anymore: the server is gone
+        assert_eq!(submit(1, Part::One, 24000), Ok(None));
+        assert_eq!(submit(1, Part::Two, 50), Err("50 was already rejected".to_owned()));
+
+        let answers = Answers::load(&answers_path).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Integer(24000)));
+        assert_eq!(answers.get(1, Part::Two), None);
+
+        let rejections = Rejections::load(&rejections_path).unwrap();
+        assert_eq!(rejections.to_csv(), "day,part,hint,answer\n1,2,too high,100\n1,2,too low,20\n1,2,,50\n");
+        assert_eq!(rejections.check(1, Part::Two, "120"), Err("120 is higher than 100, which is too high".to_owned()));
+        assert_eq!(rejections.check(1, Part::Two, "7"), Err("7 is lower than 20, which is too low".to_owned()));
+        assert!(rejections.check(1, Part::Two, "60").is_ok());
+    }
+}
@@ -0,0 +1,298 @@
+//! Watching simulations step by step.
+//!
+//! A day implementing [`Visualize`] turns its input into a sequence of [`Frame`]s, which can be
+//! played in the terminal (see [`play`]) or exported as an [asciicast v2] recording (see
+//! [`write_asciicast`]), to be replayed with `asciinema play`.
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    str::FromStr,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{grid::Grid, runner::json_string, AocError, Part, Solution};
+
+/// Colours of the terminal's standard palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    // ANSI code selecting the colour as the foreground
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// Character of a frame, in the default colour of the terminal or in one of its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn plain(symbol: char) -> Cell {
+        Cell { symbol, colour: None }
+    }
+
+    pub fn coloured(symbol: char, colour: Colour) -> Cell {
+        Cell { symbol, colour: Some(colour) }
+    }
+}
+
+/// State of a simulation at one step: a picture and a caption shown under it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>, caption: impl Into<String>) -> Frame {
+        Frame { cells, caption: caption.into() }
+    }
+
+    /// Lines taken by the frame once rendered
+    pub fn height(&self) -> usize {
+        self.cells.height() + 2 // A blank line separates the caption from the picture
+    }
+
+    /// Columns taken by the frame once rendered
+    pub fn width(&self) -> usize {
+        self.cells.width().max(self.caption.chars().count())
+    }
+
+    /// Renders the frame as text, with ANSI escape sequences for the colours if `colour` is set
+    pub fn render(&self, colour: bool) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if colour && cell.colour != current {
+                    match cell.colour {
+                        Some(colour) => write!(text, "\x1b[{}m", colour.ansi_code()).unwrap(),
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = cell.colour;
+                }
+                text.push(cell.symbol);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        write!(text, "\n{}", self.caption).unwrap();
+        text
+    }
+}
+
+/// Frames of an animation, computed as they are played
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// A solution whose simulation can be watched
+pub trait Visualize: Solution {
+    /// Frames of the simulation of a part, from its initial state to the one giving the answer, or an
+    /// error if the input can't be simulated
+    fn frames(input: Self::Input, part: Part) -> Result<Frames, AocError>;
+}
+
+/// Command changing how an animation is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl FromStr for Control {
+    type Err = String;
+
+    fn from_str(command: &str) -> Result<Control, String> {
+        match command.trim() {
+            "" | "p" => Ok(Control::TogglePause),
+            "n" => Ok(Control::Step),
+            "+" => Ok(Control::Faster),
+            "-" => Ok(Control::Slower),
+            "q" => Ok(Control::Quit),
+            command => Err(format!("unknown command '{command}'")),
+        }
+    }
+}
+
+/// Help line shown under the frames by the player
+pub const CONTROLS_HELP: &str = "Enter: pause/resume, n Enter: next frame, + or - Enter: speed, q Enter: quit";
+
+/* Reads the controls typed on stdin, one per line (the terminal stays in line mode, so each of
+ * them is only sent on Enter). Unknown commands are ignored */
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Ok(control) = line.parse() {
+                if sender.send(control).is_err() {
+                    break; // The player is done
+                }
+            }
+        }
+    });
+    receiver
+}
+
+/// How frames are played
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayOptions {
+    pub fps: f64,
+    pub colour: bool,
+    pub paused: bool, // Starting paused, to go through the frames one by one
+}
+
+// Frame rates the player keeps to: below MIN_FPS, the wait between frames is too long to be a Duration
+pub const MIN_FPS: f64 = 0.1;
+pub const MAX_FPS: f64 = 1000.0;
+
+/* Plays the frames on 'out', redrawing the screen for each of them, and returns how many were shown.
+ *
+ * While playing, the player waits for the next frame or for a control, whichever comes first. Once
+ * 'controls' is closed (stdin reached its end, for instance), the animation plays to its end */
+pub fn play(frames: impl Iterator<Item = Frame>, options: PlayOptions, controls: &Receiver<Control>, out: &mut impl Write) -> io::Result<usize> {
+    let mut fps = options.fps.clamp(MIN_FPS, MAX_FPS);
+    let mut paused = options.paused;
+    let mut shown = 0;
+
+    write!(out, "\x1b[?25l")?; // Hiding the cursor
+    'frames: for frame in frames {
+        write!(out, "\x1b[H\x1b[J{}\n{CONTROLS_HELP}", frame.render(options.colour))?;
+        out.flush()?;
+        shown += 1;
+
+        loop {
+            let control = if paused {
+                match controls.recv() {
+                    Ok(control) => control,
+                    Err(_) => { // Nobody can resume the animation anymore
+                        paused = false;
+                        continue;
+                    },
+                }
+            }
+            else {
+                match controls.recv_timeout(Duration::from_secs_f64(1.0 / fps)) {
+                    Ok(control) => control,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(Duration::from_secs_f64(1.0 / fps));
+                        break;
+                    },
+                }
+            };
+
+            match control {
+                Control::TogglePause => paused = !paused,
+                Control::Step if paused => break,
+                Control::Step => paused = true,
+                Control::Faster => fps = (fps * 2.0).min(MAX_FPS),
+                Control::Slower => fps = (fps / 2.0).max(MIN_FPS),
+                Control::Quit => break 'frames,
+            }
+        }
+    }
+    writeln!(out, "\x1b[?25h")?;
+    Ok(shown)
+}
+
+// Narrowest terminal of a recording, so the captions (which grow along an animation) fit
+pub const CAST_MIN_WIDTH: usize = 80;
+
+/* Writes the frames as an asciicast v2 recording, showing 'fps' frames per second, and returns how
+ * many were written. Frames are written as they are computed: the size of the terminal, in the
+ * header, is the one of the first frame (every frame of an animation has the same picture size),
+ * at least CAST_MIN_WIDTH columns wide */
+pub fn write_asciicast(frames: impl Iterator<Item = Frame>, fps: f64, colour: bool, title: &str, out: &mut impl Write) -> io::Result<usize> {
+    let mut frames = frames.peekable();
+    let (width, height) = frames.peek().map_or((0, 0), |frame| (frame.width().max(CAST_MIN_WIDTH), frame.height()));
+
+    writeln!(out, "{{\"version\": 2, \"width\": {width}, \"height\": {height}, \"title\": {}}}", json_string(title))?;
+    let mut written = 0;
+    for (idx, frame) in frames.enumerate() {
+        let screen = format!("\x1b[H\x1b[J{}", frame.render(colour).replace('\n', "\r\n"));
+        writeln!(out, "[{:.6}, \"o\", {}]", idx as f64 / fps, json_string(&screen))?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(caption: &str) -> Frame {
+        let mut cells = Grid::new(3, 1, Cell::plain('.'));
+        cells.set(1, 0, Cell::coloured('#', Colour::Green));
+        Frame::new(cells, caption)
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(frame("step 1").render(false), ".#.\n\nstep 1");
+        assert_eq!(frame("step 1").render(true), ".\x1b[32m#\x1b[0m.\n\nstep 1");
+        assert_eq!((frame("step 1").width(), frame("step 1").height()), (6, 3));
+    }
+
+    #[test]
+    fn test_play() {
+        let options = PlayOptions { fps: MAX_FPS, colour: false, paused: false };
+        let (sender, controls) = mpsc::channel();
+        let mut screen = Vec::new();
+
+        drop(sender); // Without controls, every frame is shown
+        assert_eq!(play([frame("1"), frame("2")].into_iter(), options, &controls, &mut screen).unwrap(), 2);
+        assert!(String::from_utf8(screen).unwrap().contains("\x1b[H\x1b[J.#.\n\n2\n"));
+
+        let (sender, controls) = mpsc::channel();
+        sender.send(Control::Step).unwrap();
+        sender.send(Control::Quit).unwrap();
+        let paused = PlayOptions { paused: true, ..options };
+        assert_eq!(play([frame("1"), frame("2"), frame("3")].into_iter(), paused, &controls, &mut Vec::new()).unwrap(), 2);
+
+        // Neither a tiny frame rate nor slowing down repeatedly goes below MIN_FPS
+        let (sender, controls) = mpsc::channel();
+        (0..100).for_each(|_| sender.send(Control::Slower).unwrap());
+        sender.send(Control::Quit).unwrap();
+        let slow = PlayOptions { fps: 1e-300, ..options };
+        assert_eq!(play([frame("1"), frame("2")].into_iter(), slow, &controls, &mut Vec::new()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_write_asciicast() {
+        let mut recording = Vec::new();
+        assert_eq!(write_asciicast([frame("1"), frame("22")].into_iter(), 4.0, true, "Day 0", &mut recording).unwrap(), 2);
+
+        let recording = String::from_utf8(recording).unwrap();
+        let lines: Vec<&str> = recording.lines().collect();
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 80, \"height\": 3, \"title\": \"Day 0\"}");
+        assert_eq!(lines[2], "[0.250000, \"o\", \"\\u001b[H\\u001b[J.\\u001b[32m#\\u001b[0m.\\r\\n\\r\\n22\"]");
+    }
+}
@@ -0,0 +1,234 @@
+//! Re-running a day whenever its inputs change.
+//!
+//! The files of a day (`input/<year>/dayN.txt` and `examples/<year>/dayN/*.txt`) are polled for changes. Each
+//! time one of them changes, the day is solved again on every input, and the answers are shown
+//! next to the ones of the previous run.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    examples, input,
+    registry::Puzzle,
+    runner::{panic_message, without_panic_messages},
+    Answer, Part,
+};
+
+/// How often the files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Name under which the answers of the real input are shown
+pub const INPUT_NAME: &str = "input";
+
+/// Files whose changes trigger a new run of a puzzle
+pub fn watched_files(puzzle: &Puzzle) -> Vec<PathBuf> {
+    let mut files = vec![input::default_path(puzzle.year, puzzle.day)];
+    if let Ok(entries) = fs::read_dir(examples::examples_dir(puzzle.year, puzzle.day)) {
+        let mut examples: Vec<PathBuf> = entries.filter_map(|entry| Some(entry.ok()?.path())).collect();
+        examples.sort();
+        files.extend(examples);
+    }
+    files
+}
+
+/// Modification times and sizes of a set of files (`None` for the ones that don't exist)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<(SystemTime, u64)>>);
+
+impl Snapshot {
+    pub fn of(files: &[PathBuf]) -> Snapshot {
+        let times = files
+            .iter()
+            .map(|path| {
+                let metadata = fs::metadata(path).ok();
+                (path.clone(), metadata.and_then(|metadata| Some((metadata.modified().ok()?, metadata.len()))))
+            })
+            .collect();
+        Snapshot(times)
+    }
+
+    /// Snapshot of the watched files of a puzzle
+    pub fn take(puzzle: &Puzzle) -> Snapshot {
+        Snapshot::of(&watched_files(puzzle))
+    }
+}
+
+/// Answer of one part on one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub answer: Result<Answer, String>,
+    pub expected: Option<Answer>, // Only examples have expected answers
+}
+
+/// Answers of a run, by input name and part
+pub type Outcome = BTreeMap<(String, Part), Entry>;
+
+/* Solves both parts of an input. Solvers still being written may panic (with 'todo!()', for instance):
+ * their message is shown with the answers instead of being printed by the panic hook */
+fn solve(puzzle: &Puzzle, input: &str) -> [Result<Answer, String>; 2] {
+    without_panic_messages(|| {
+        let parsed = match panic::catch_unwind(|| puzzle.parse(input)) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(err)) => return [Err(err.to_string()), Err(err.to_string())],
+            Err(payload) => {
+                let err = format!("the parser panicked: {}", panic_message(&*payload));
+                return [Err(err.clone()), Err(err)];
+            },
+        };
+        Part::BOTH.map(|part| {
+            panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part)))
+                .map_err(|payload| format!("the solver panicked: {}", panic_message(&*payload)))
+        })
+    })
+}
+
+/// Solves a day on each of its examples and on its real input
+pub fn evaluate(puzzle: &Puzzle) -> Outcome {
+    let mut outcome = Outcome::new();
+    let mut add = |name: &str, answers: [Result<Answer, String>; 2], expected: [Option<Answer>; 2]| {
+        for ((part, answer), expected) in Part::BOTH.into_iter().zip(answers).zip(expected) {
+            outcome.insert((name.to_owned(), part), Entry { answer, expected });
+        }
+    };
+
+    match examples::load_all(puzzle.year, puzzle.day) {
+        Ok(examples) => {
+            for example in examples {
+                let expected = Part::BOTH.map(|part| example.expected(puzzle.day, part).cloned());
+                add(&example.name, solve(puzzle, &example.input), expected);
+            }
+        },
+        Err(err) => add("examples", [Err(err.clone()), Err(err)], [None, None]),
+    }
+
+    match input::InputSource::Default.read(puzzle.year, puzzle.day) {
+        Ok(input) => add(INPUT_NAME, solve(puzzle, &input), [None, None]),
+        Err(err) => add(INPUT_NAME, [Err(err.to_string()), Err(err.to_string())], [None, None]),
+    }
+    outcome
+}
+
+// Answer (or error) as shown in the report; multi-line answers go on the following lines
+fn describe(answer: &Result<Answer, String>) -> String {
+    match answer {
+        Ok(answer) => {
+            let text = answer.to_string();
+            if text.contains('\n') {
+                text.lines().fold(String::new(), |described, line| described + "\n      " + line)
+            }
+            else {
+                text
+            }
+        },
+        Err(err) => format!("error: {err}"),
+    }
+}
+
+/* Renders the answers of a run, compared to the previous one.
+ *
+ * Each line starts with a marker: '~' for answers that changed, '+' for new ones, '-' for the ones
+ * that disappeared (like a deleted example), and nothing otherwise. Examples also show whether
+ * they match their expected answers */
+pub fn render_changes(previous: Option<&Outcome>, current: &Outcome) -> String {
+    let mut report = String::new();
+
+    for ((name, part), entry) in current {
+        let previous_entry = previous.map(|previous| previous.get(&(name.clone(), *part)));
+        let marker = match previous_entry {
+            None => ' ',
+            Some(None) => '+',
+            Some(Some(previous)) if previous.answer != entry.answer => '~',
+            Some(Some(_)) => ' ',
+        };
+
+        write!(report, "{marker} {name} part {part}: {}", describe(&entry.answer)).unwrap();
+        match (&entry.answer, &entry.expected) {
+            (Ok(answer), Some(expected)) if answer == expected => report.push_str(" (ok)"),
+            (_, Some(expected)) => write!(report, " (expected {expected})").unwrap(),
+            (_, None) => (),
+        }
+        if let (Some(Some(previous)), '~') = (previous_entry, marker) {
+            write!(report, " (was {})", describe(&previous.answer)).unwrap();
+        }
+        report.push('\n');
+    }
+
+    for (name, part) in previous.into_iter().flat_map(|previous| previous.keys()) {
+        if !current.contains_key(&(name.clone(), *part)) {
+            writeln!(report, "- {name} part {part}").unwrap();
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::registry;
+
+    fn entry(answer: Result<i64, &str>, expected: Option<i64>) -> Entry {
+        Entry {
+            answer: answer.map(Answer::Integer).map_err(str::to_owned),
+            expected: expected.map(Answer::Integer),
+        }
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-2022-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let files = [dir.join("input.txt"), dir.join("missing.txt")];
+
+        fs::write(&files[0], "1").unwrap();
+        let before = Snapshot::of(&files);
+        assert_eq!(Snapshot::of(&files), before);
+
+        let modified = SystemTime::now() + Duration::from_secs(5);
+        fs::File::options().write(true).open(&files[0]).unwrap().set_modified(modified).unwrap();
+        assert_ne!(Snapshot::of(&files), before);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let outcome = evaluate(registry::find(2022, 1).unwrap());
+
+        let example = &outcome[&("example".to_owned(), Part::One)];
+        assert_eq!(example.answer, Ok(Answer::Integer(24000)));
+        assert_eq!(example.expected, Some(Answer::Integer(24000)));
+        assert!(outcome.contains_key(&(INPUT_NAME.to_owned(), Part::Two)));
+    }
+
+    #[test]
+    fn test_render_changes() {
+        let previous = Outcome::from([
+            (("example".to_owned(), Part::One), entry(Ok(10), Some(10))),
+            (("example".to_owned(), Part::Two), entry(Ok(20), Some(30))),
+            (("old".to_owned(), Part::One), entry(Ok(1), None)),
+        ]);
+        let current = Outcome::from([
+            (("example".to_owned(), Part::One), entry(Ok(10), Some(10))),
+            (("example".to_owned(), Part::Two), entry(Ok(30), Some(30))),
+            (("input".to_owned(), Part::One), entry(Err("day 1: no elves"), None)),
+        ]);
+
+        assert_eq!(render_changes(None, &previous), "  example part 1: 10 (ok)\n  example part 2: 20 (expected 30)\n  old part 1: 1\n");
+
+        let expected = concat!(
+            "  example part 1: 10 (ok)\n",
+            "~ example part 2: 30 (ok) (was 20)\n",
+            "+ input part 1: error: day 1: no elves\n",
+            "- old part 1\n",
+        );
+        assert_eq!(render_changes(Some(&previous), &current), expected);
+    }
+}
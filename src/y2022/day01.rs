@@ -0,0 +1,602 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{self, Write},
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+use crate::{bench::percentile_index, AocError, Solution};
+
+// Food items carried by one Elf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub number: usize,     // Position of the Elf in the inventory, from 1
+    pub first_line: usize, // Line of its first item, from 1
+    pub items: Vec<u64>,   // Calories of each item
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+}
+
+/* Reads an inventory lazily, yielding the items of one Elf at a time, so only one Elf is held in
+ * memory. Groups are separated by one or more blank lines, and the last one doesn't need a blank
+ * line after it. Line endings (LF or CRLF) and whitespace around the numbers are ignored */
+pub struct Inventory<R> {
+    lines: io::Lines<R>,
+    line_number: usize,
+    elves: usize,
+}
+
+impl<R: BufRead> Inventory<R> {
+    pub fn new(reader: R) -> Inventory<R> {
+        Inventory { lines: reader.lines(), line_number: 0, elves: 0 }
+    }
+}
+
+impl<R: BufRead> Iterator for Inventory<R> {
+    type Item = Result<Elf, AocError>;
+
+    fn next(&mut self) -> Option<Result<Elf, AocError>> {
+        let mut elf: Option<Elf> = None;
+
+        loop {
+            let line = match self.lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(error)) => return Some(Err(AocError::Io { source: "the inventory".to_owned(), error })),
+                None => return elf.map(Ok), // The last Elf may not be followed by a blank line
+            };
+            self.line_number += 1;
+
+            let calories = line.trim();
+            if calories.is_empty() { // Blank line, the Elf changes (if there was one)
+                match elf {
+                    Some(elf) => return Some(Ok(elf)),
+                    None => continue,
+                }
+            }
+
+            let Ok(item) = calories.parse() else {
+                return Some(Err(AocError::parse(Day1::DAY, self.line_number, calories, "a number of calories")));
+            };
+            let elf = elf.get_or_insert_with(|| {
+                self.elves += 1;
+                Elf { number: self.elves, first_line: self.line_number, items: Vec::new() }
+            });
+            elf.items.push(item);
+        }
+    }
+}
+
+// Elf among the ones carrying the most calories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopElf<'a> {
+    pub elf: &'a Elf,
+    pub total: u64,
+}
+
+/* Finds the 'k' Elves carrying the most calories, from the most to the least. Elves carrying the
+ * same number of calories are ranked by their position in the inventory (the first one wins).
+ *
+ * Only the 'k' best Elves so far are kept, in a heap whose top is the weakest of them, so every
+ * other Elf is only compared with that one */
+pub fn top_elves(elves: &[Elf], k: usize) -> Result<Vec<TopElf<'_>>, AocError> {
+    if k > elves.len() {
+        return Err(AocError::invalid(Day1::DAY, format!("the top {k} Elves were asked for, but the inventory lists {}", elves.len())));
+    }
+
+    let mut selected: BinaryHeap<(Reverse<u64>, usize)> = BinaryHeap::with_capacity(k); // The greatest key is the weakest Elf
+    for (idx, elf) in elves.iter().enumerate() {
+        let key = (Reverse(elf.total()), idx);
+        if selected.len() < k {
+            selected.push(key);
+        }
+        else if let Some(mut weakest) = selected.peek_mut() {
+            if key < *weakest {
+                *weakest = key;
+            }
+        }
+    }
+
+    Ok(selected
+        .into_sorted_vec()
+        .into_iter()
+        .map(|(Reverse(total), idx)| TopElf { elf: &elves[idx], total })
+        .collect())
+}
+
+// Percentiles of the totals shown by the statistics
+pub const PERCENTILES: [usize; 5] = [10, 25, 75, 90, 99];
+
+// Range of totals in the histogram, and how many Elves carry a total in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bin {
+    pub from: u64,
+    pub to: u64, // Inclusive
+    pub elves: usize,
+}
+
+// Statistics of the calories carried by the Elves of an inventory
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub elves: usize,
+    pub items: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,                   // Population standard deviation
+    pub percentiles: Vec<(usize, u64)>, // Nearest-rank percentiles, like the ones of the benchmarks
+    pub items_per_elf: (usize, f64, usize), // Minimum, mean and maximum
+    pub histogram: Vec<Bin>,
+}
+
+impl Statistics {
+    // Computes the statistics of a non-empty inventory, sorting the totals in 'bins' bins of the same width
+    pub fn of(elves: &[Elf], bins: usize) -> Statistics {
+        assert!(!elves.is_empty(), "Statistics need at least one Elf");
+        let mut totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+        totals.sort_unstable();
+
+        let count = totals.len();
+        let mean = totals.iter().sum::<u64>() as f64 / count as f64;
+        let median = match count % 2 {
+            0 => (totals[count / 2 - 1] + totals[count / 2]) as f64 / 2.0,
+            _ => totals[count / 2] as f64,
+        };
+        let variance = totals.iter().map(|&total| (total as f64 - mean).powi(2)).sum::<f64>() / count as f64;
+
+        let items: Vec<usize> = elves.iter().map(|elf| elf.items.len()).collect();
+        let items_count = items.iter().sum();
+
+        Statistics {
+            elves: count,
+            items: items_count,
+            min: totals[0],
+            max: totals[count - 1],
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES.iter().map(|&p| (p, totals[percentile_index(count, p)])).collect(),
+            items_per_elf: (*items.iter().min().unwrap(), items_count as f64 / count as f64, *items.iter().max().unwrap()),
+            histogram: histogram(&totals, bins),
+        }
+    }
+
+    pub fn render_text(&self) -> String {
+        let mut text = String::new();
+        let (min_items, mean_items, max_items) = self.items_per_elf;
+        writeln!(text, "Elves:              {}", self.elves).unwrap();
+        writeln!(text, "Items:              {} ({min_items} to {max_items} per Elf, {mean_items:.1} on average)", self.items).unwrap();
+        writeln!(text, "Calories per Elf:").unwrap();
+        writeln!(text, "  min               {}", self.min).unwrap();
+        writeln!(text, "  max               {}", self.max).unwrap();
+        writeln!(text, "  mean              {:.1}", self.mean).unwrap();
+        writeln!(text, "  median            {:.1}", self.median).unwrap();
+        writeln!(text, "  std dev           {:.1}", self.std_dev).unwrap();
+        for (percentile, total) in &self.percentiles {
+            writeln!(text, "  p{percentile:<17}{total}").unwrap();
+        }
+
+        // Bars are scaled so the longest one is 40 characters long
+        writeln!(text, "Histogram:").unwrap();
+        let most_elves = self.histogram.iter().map(|bin| bin.elves).max().unwrap_or(0).max(1);
+        let width = self.max.to_string().len();
+        for bin in &self.histogram {
+            let bar = "#".repeat((bin.elves * 40).div_ceil(most_elves));
+            writeln!(text, "  {:>width$} - {:>width$} | {bar} {}", bin.from, bin.to, bin.elves).unwrap();
+        }
+        text
+    }
+
+    pub fn render_json(&self) -> String {
+        let (min_items, mean_items, max_items) = self.items_per_elf;
+        let percentiles: Vec<String> = self.percentiles.iter().map(|(p, total)| format!("\"p{p}\": {total}")).collect();
+        let bins: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bin| format!("    {{\"from\": {}, \"to\": {}, \"elves\": {}}}", bin.from, bin.to, bin.elves))
+            .collect();
+
+        let mut json = String::new();
+        writeln!(json, "{{").unwrap();
+        writeln!(json, "  \"elves\": {},", self.elves).unwrap();
+        writeln!(json, "  \"items\": {},", self.items).unwrap();
+        writeln!(json, "  \"items_per_elf\": {{\"min\": {min_items}, \"mean\": {mean_items}, \"max\": {max_items}}},").unwrap();
+        writeln!(
+            json,
+            "  \"calories\": {{\"min\": {}, \"max\": {}, \"mean\": {}, \"median\": {}, \"std_dev\": {}, {}}},",
+            self.min, self.max, self.mean, self.median, self.std_dev, percentiles.join(", "),
+        ).unwrap();
+        writeln!(json, "  \"histogram\": [\n{}\n  ]", bins.join(",\n")).unwrap();
+        writeln!(json, "}}").unwrap();
+        json
+    }
+}
+
+// Counts the sorted totals in 'bins' ranges of the same width, from the smallest total to the largest
+fn histogram(totals: &[u64], bins: usize) -> Vec<Bin> {
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+    let width = (max - min + 1).div_ceil(bins.max(1) as u64);
+
+    let mut histogram: Vec<Bin> = (0..bins.max(1) as u64)
+        .map(|idx| Bin { from: min + idx * width, to: (min + (idx + 1) * width - 1).min(max), elves: 0 })
+        .take_while(|bin| bin.from <= max) // Fewer bins than asked when there are fewer distinct totals
+        .collect();
+    for &total in totals {
+        histogram[((total - min) / width) as usize].elves += 1;
+    }
+    histogram
+}
+
+// How the snacks are redistributed by 'rebalance'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    // Exact for small inventories, the better of the approximations otherwise
+    Auto,
+    // Smallest possible heaviest load; the search grows exponentially with the number of items
+    Exact,
+    // Each item, from the largest, goes to the Elf carrying the least
+    Greedy,
+    // Largest differencing method, generalized to any number of Elves
+    KarmarkarKarp,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Method::Auto => "auto",
+            Method::Exact => "exact",
+            Method::Greedy => "greedy",
+            Method::KarmarkarKarp => "karmarkar-karp",
+        })
+    }
+}
+
+impl FromStr for Method {
+    type Err = String;
+
+    fn from_str(method: &str) -> Result<Method, String> {
+        match method {
+            "auto" => Ok(Method::Auto),
+            "exact" => Ok(Method::Exact),
+            "greedy" => Ok(Method::Greedy),
+            "karmarkar-karp" | "kk" => Ok(Method::KarmarkarKarp),
+            _ => Err(format!("unknown method '{method}', expected auto, exact, greedy or karmarkar-karp")),
+        }
+    }
+}
+
+// Largest inventory (in items) solved exactly by the automatic method
+pub const EXACT_MAX_ITEMS: usize = 24;
+
+// Items given to each Elf
+type Loads = Vec<Vec<u64>>;
+
+fn max_load(loads: &Loads) -> u64 {
+    loads.iter().map(|items| items.iter().sum()).max().unwrap_or(0)
+}
+
+// Snacks redistributed between the Elves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rebalancing {
+    pub method: Method,   // Never 'Auto': the method that was actually used
+    pub loads: Loads,     // Items of each Elf, the heaviest load first
+    pub before: Vec<u64>, // Loads of the inventory, the heaviest first
+    pub lower_bound: u64, // No redistribution can have a lighter heaviest load
+}
+
+impl Rebalancing {
+    pub fn max_load(&self) -> u64 {
+        max_load(&self.loads)
+    }
+
+    pub fn render(&self) -> String {
+        let total = |items: &Vec<u64>| items.iter().sum::<u64>();
+        let (before_max, after_max) = (self.before.first().copied().unwrap_or(0), self.max_load());
+        let (before_min, after_min) = (self.before.last().copied().unwrap_or(0), self.loads.iter().map(total).min().unwrap_or(0));
+        let saved = before_max.saturating_sub(after_max); // Never negative: the inventory's loads are kept otherwise
+
+        let mut text = String::new();
+        writeln!(text, "Method:          {}", self.method).unwrap();
+        writeln!(text, "Heaviest load:   {before_max} -> {after_max} (-{saved}, -{:.1}%)", saved as f64 * 100.0 / before_max.max(1) as f64).unwrap();
+        writeln!(text, "Lightest load:   {before_min} -> {after_min}").unwrap();
+        let optimal = if after_max == self.lower_bound { " (reached, so the loads are optimal)" } else { "" };
+        writeln!(text, "Lower bound:     {}{optimal}", self.lower_bound).unwrap();
+
+        writeln!(text, "Loads:").unwrap();
+        for (idx, items) in self.loads.iter().enumerate() {
+            let plural = if items.len() == 1 { "" } else { "s" };
+            writeln!(text, "  {:>4}: {:>8} ({} item{plural})", idx + 1, total(items), items.len()).unwrap();
+        }
+        text
+    }
+}
+
+/* Redistributes the snacks of the Elves, without splitting any of them, so that the heaviest load
+ * is as light as possible. This is multiway number partitioning, with as many parts as Elves */
+pub fn rebalance(elves: &[Elf], method: Method) -> Rebalancing {
+    let mut items: Vec<u64> = elves.iter().flat_map(|elf| elf.items.iter().copied()).collect();
+    items.sort_unstable_by(|a, b| b.cmp(a));
+    let k = elves.len().max(1);
+
+    let total: u64 = items.iter().sum();
+    let lower_bound = total.div_ceil(k as u64).max(items.first().copied().unwrap_or(0));
+
+    // The approximations can do worse than the inventory, whose loads are then kept as they are
+    let mut original: Loads = elves.iter().map(|elf| elf.items.clone()).collect();
+    original.resize(k, Vec::new());
+    let no_worse = |loads: Loads| if max_load(&loads) <= max_load(&original) { loads } else { original.clone() };
+
+    let approximate = || {
+        let greedy = no_worse(greedy(&items, k));
+        let karmarkar_karp = no_worse(karmarkar_karp(&items, k));
+        match max_load(&greedy) < max_load(&karmarkar_karp) {
+            true => (Method::Greedy, greedy),
+            false => (Method::KarmarkarKarp, karmarkar_karp),
+        }
+    };
+    let (method, mut loads) = match method {
+        Method::Auto if items.len() > EXACT_MAX_ITEMS => approximate(),
+        Method::Auto | Method::Exact => {
+            let (_, initial) = approximate();
+            (Method::Exact, exact(&items, k, initial, lower_bound))
+        },
+        Method::Greedy => (Method::Greedy, no_worse(greedy(&items, k))),
+        Method::KarmarkarKarp => (Method::KarmarkarKarp, no_worse(karmarkar_karp(&items, k))),
+    };
+
+    loads.sort_by_key(|items| Reverse(items.iter().sum::<u64>()));
+    let mut before: Vec<u64> = elves.iter().map(Elf::total).collect();
+    before.sort_unstable_by(|a, b| b.cmp(a));
+    Rebalancing { method, loads, before, lower_bound }
+}
+
+// Gives each item (sorted from the largest) to the Elf carrying the least so far
+fn greedy(items: &[u64], k: usize) -> Loads {
+    let mut loads = vec![Vec::new(); k];
+    let mut lightest: BinaryHeap<Reverse<(u64, usize)>> = (0..k).map(|idx| Reverse((0, idx))).collect();
+
+    for &item in items {
+        let Reverse((load, idx)) = lightest.pop().expect("There's at least one Elf");
+        loads[idx].push(item);
+        lightest.push(Reverse((load + item, idx)));
+    }
+    loads
+}
+
+// Parts of a partial partition, the heaviest first, with their sums
+type Parts = Vec<(u64, Vec<u64>)>;
+
+/* Starts from one partial partition per item (the item alone, and k - 1 empty parts), then keeps
+ * merging the two partitions whose parts are the most uneven: the heaviest part of one is combined
+ * with the lightest of the other, and so on */
+fn karmarkar_karp(items: &[u64], k: usize) -> Loads {
+    let mut partitions: Vec<Option<Parts>> = items
+        .iter()
+        .map(|&item| Some(std::iter::once((item, vec![item])).chain((1..k).map(|_| (0, Vec::new()))).collect()))
+        .collect();
+    let spread = |parts: &Parts| parts[0].0 - parts[parts.len() - 1].0;
+    let mut uneven: BinaryHeap<(u64, usize)> = partitions.iter().enumerate().map(|(idx, parts)| (spread(parts.as_ref().unwrap()), idx)).collect();
+
+    while uneven.len() > 1 {
+        let (_, first) = uneven.pop().unwrap();
+        let (_, second) = uneven.pop().unwrap();
+        let first = partitions[first].take().unwrap();
+        let second = partitions[second].take().unwrap();
+
+        let mut merged: Parts = first
+            .into_iter()
+            .zip(second.into_iter().rev())
+            .map(|((sum, mut items), (other_sum, other_items))| {
+                items.extend(other_items);
+                (sum + other_sum, items)
+            })
+            .collect();
+        merged.sort_by_key(|(sum, _)| Reverse(*sum));
+
+        uneven.push((spread(&merged), partitions.len()));
+        partitions.push(Some(merged));
+    }
+
+    match uneven.pop() {
+        Some((_, idx)) => partitions[idx].take().unwrap().into_iter().map(|(_, items)| items).collect(),
+        None => vec![Vec::new(); k], // No items at all
+    }
+}
+
+/* Branch and bound over the assignments of the items (sorted from the largest), starting from the
+ * loads of an approximation. An item never goes to an Elf whose load would reach the best heaviest
+ * load so far, nor to two Elves carrying the same load (they lead to the same partitions), and the
+ * search stops as soon as the lower bound is reached */
+fn exact(items: &[u64], k: usize, initial: Loads, lower_bound: u64) -> Loads {
+    struct Search<'a> {
+        items: &'a [u64],
+        lower_bound: u64,
+        best: Loads,
+        best_max: u64,
+        loads: Vec<u64>,
+        assignment: Vec<usize>,
+    }
+
+    impl Search<'_> {
+        fn assign(&mut self, idx: usize) {
+            if self.best_max == self.lower_bound {
+                return; // Nothing can be better
+            }
+            if idx == self.items.len() {
+                let mut best = vec![Vec::new(); self.loads.len()];
+                for (&item, &elf) in self.items.iter().zip(&self.assignment) {
+                    best[elf].push(item);
+                }
+                self.best_max = *self.loads.iter().max().unwrap();
+                self.best = best;
+                return;
+            }
+
+            let item = self.items[idx];
+            for elf in 0..self.loads.len() {
+                let load = self.loads[elf];
+                if load + item >= self.best_max || self.loads[..elf].contains(&load) {
+                    continue;
+                }
+                self.loads[elf] += item;
+                self.assignment.push(elf);
+                self.assign(idx + 1);
+                self.assignment.pop();
+                self.loads[elf] -= item;
+            }
+        }
+    }
+
+    let best_max = max_load(&initial);
+    let mut search = Search { items, lower_bound, best: initial, best_max, loads: vec![0; k], assignment: Vec::new() };
+    search.assign(0);
+    search.best
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<Elf>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    // Reading the inventory of calories, Elf by Elf
+    fn parse(inventory: &str) -> Result<Vec<Elf>, AocError> {
+        let elves = Inventory::new(inventory.as_bytes()).collect::<Result<Vec<Elf>, AocError>>()?;
+
+        if elves.is_empty() {
+            return Err(AocError::invalid(Day1::DAY, "the inventory doesn't list any Elf"));
+        }
+        Ok(elves)
+    }
+
+    fn part_one(elves: &Vec<Elf>) -> u64 {
+        elves.iter().map(Elf::total).max().expect("Empty inventory!")
+    }
+
+    // Calories carried by the top three Elves (or by all of them, if there are fewer)
+    fn part_two(elves: &Vec<Elf>) -> u64 {
+        let top_three = top_elves(elves, elves.len().min(3)).expect("There can't be more Elves than listed");
+        top_three.iter().map(|top| top.total).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(inventory: &str) -> Vec<u64> {
+        Day1::parse(inventory).unwrap().iter().map(Elf::total).collect()
+    }
+
+    #[test]
+    fn test_inventory() {
+        assert_eq!(totals("1000\n2000\n\n3000\n"), [3000, 3000]);
+        assert_eq!(totals("1000\n2000\n\n3000"), [3000, 3000]); // No blank line after the last Elf
+        assert_eq!(totals("1000\r\n2000 \r\n\r\n\r\n  3000\r\n"), [3000, 3000]);
+        assert_eq!(totals("\n\n4000\n\n"), [4000]);
+
+        let elves = Day1::parse("1\n\n\n2\n3\n").unwrap();
+        assert_eq!((elves[1].number, elves[1].first_line, elves[1].items.clone()), (2, 4, vec![2, 3]));
+    }
+
+    #[test]
+    fn test_inventory_errors() {
+        let mut inventory = Inventory::new("1000\n\n20x0\n".as_bytes());
+        assert!(inventory.next().unwrap().is_ok());
+
+        let err = inventory.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "day 1, line 3: expected a number of calories, found '20x0'");
+        assert!(Day1::parse("\n \n").is_err());
+    }
+
+    // Reading through a buffer, as with a file, with CRLF line endings and a trailing blank line
+    #[test]
+    fn test_inventory_reader() {
+        let reader = io::BufReader::new(io::Cursor::new("1000\r\n2000\r\n\r\n3000\r\n\r\n"));
+        let totals: Vec<u64> = Inventory::new(reader).map(|elf| elf.unwrap().total()).collect();
+        assert_eq!(totals, [3000, 3000]);
+
+        let mut inventory = Inventory::new(io::BufReader::new(io::Cursor::new("1000\r\n\r\n 20x0 \r\n\r\n")));
+        inventory.next();
+        let err = inventory.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "day 1, line 3: expected a number of calories, found '20x0'");
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let elves = Day1::parse("1\n2\n\n5\n\n3\n\n4\n1\n\n2").unwrap();
+        let top = |k| top_elves(&elves, k).unwrap().iter().map(|top| (top.elf.number, top.total)).collect::<Vec<_>>();
+
+        assert_eq!(top(3), [(2, 5), (4, 5), (1, 3)]); // Elves 2 and 4 carry as much; elf 3 loses to elf 1
+        assert_eq!(top(5).len(), 5);
+        assert!(top(0).is_empty());
+        assert_eq!(top_elves(&elves, 2).unwrap()[1].elf.items, [4, 1]);
+
+        let err = top_elves(&elves, 6).unwrap_err();
+        assert_eq!(err.to_string(), "day 1: the top 6 Elves were asked for, but the inventory lists 5");
+    }
+
+    #[test]
+    fn test_statistics() {
+        let example = crate::examples::load(2022, 1, "example").unwrap();
+        let statistics = Statistics::of(&Day1::parse(&example.input).unwrap(), 2);
+
+        assert_eq!((statistics.elves, statistics.items, statistics.items_per_elf), (5, 10, (1, 2.0, 3)));
+        assert_eq!((statistics.min, statistics.max, statistics.mean, statistics.median), (4000, 24000, 11000.0, 10000.0));
+        assert_eq!(format!("{:.1}", statistics.std_dev), "6985.7");
+        assert_eq!(statistics.percentiles, [(10, 4000), (25, 6000), (75, 11000), (90, 24000), (99, 24000)]);
+        assert_eq!(statistics.histogram, [Bin { from: 4000, to: 14000, elves: 4 }, Bin { from: 14001, to: 24000, elves: 1 }]);
+
+        assert!(statistics.render_text().contains("   4000 - 14000 | ######################################## 4\n"));
+        assert!(statistics.render_json().contains("\"calories\": {\"min\": 4000, \"max\": 24000, \"mean\": 11000, \"median\": 10000,"));
+        assert_eq!(Statistics::of(&Day1::parse("5\n\n5").unwrap(), 10).histogram, [Bin { from: 5, to: 5, elves: 2 }]);
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let elves = Day1::parse("3\n3\n2\n\n2\n2").unwrap(); // Loads of 8 and 4
+        let loads = |rebalancing: &Rebalancing| rebalancing.loads.iter().map(|items| items.iter().sum()).collect::<Vec<u64>>();
+
+        let greedy = rebalance(&elves, Method::Greedy);
+        assert_eq!((loads(&greedy), greedy.before.clone(), greedy.lower_bound), (vec![7, 5], vec![8, 4], 6));
+        assert_eq!(rebalance(&elves, Method::KarmarkarKarp).max_load(), 7);
+
+        let exact = rebalance(&elves, Method::Auto);
+        assert_eq!(exact.method, Method::Exact);
+        assert_eq!(exact.loads, [vec![3, 3], vec![2, 2, 2]]);
+        assert!(exact.render().contains("Heaviest load:   8 -> 6 (-2, -25.0%)\n"));
+    }
+
+    // Greedy splits 3, 3, 2, 2, 2 as 7 and 5, which is worse than the inventory's 6 and 6
+    #[test]
+    fn test_rebalance_never_worsens() {
+        let elves = Day1::parse("3\n3\n\n2\n2\n2").unwrap();
+        for method in [Method::Greedy, Method::KarmarkarKarp, Method::Exact] {
+            let rebalancing = rebalance(&elves, method);
+            assert_eq!(rebalancing.loads, [vec![3, 3], vec![2, 2, 2]]);
+            assert!(rebalancing.render().contains("Heaviest load:   6 -> 6 (-0, -0.0%)\n"));
+        }
+    }
+
+    #[test]
+    fn test_rebalance_large_inventory() {
+        let example = crate::examples::load(2022, 1, "example").unwrap();
+        let elves = Day1::parse(&example.input.repeat(5)).unwrap(); // 50 items, too many to be solved exactly
+        let rebalancing = rebalance(&elves, Method::Auto);
+
+        assert_ne!(rebalancing.method, Method::Exact);
+        assert_eq!(rebalancing.loads.len(), 25);
+        assert_eq!(rebalancing.loads.iter().flatten().sum::<u64>(), 5 * 55000);
+        assert!(rebalancing.max_load() < 24000);
+    }
+}
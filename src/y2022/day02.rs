@@ -0,0 +1,218 @@
+use crate::{AocError, Solution};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Win = 6,
+    Draw = 3,
+    Loss = 0,
+}
+
+impl From<Outcome> for i32 { // Conversion from Outcome to i32 based on discriminant
+    fn from(item: Outcome) -> Self {
+        item as i32
+    }
+}
+
+/* Rules of a game of the rock-paper-scissors family: its shapes, the score of each of them and what
+ * beats what. Shapes are referred to by their index */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    beats: Vec<Vec<bool>>, // beats[a][b] is set when shape 'a' beats shape 'b'
+}
+
+impl Rules {
+    /* Builds the rules from the pairs '(winner, loser)', checking that they make a fair game: no shape
+     * beats itself, every pair of shapes has exactly one winner and every shape beats as many shapes as
+     * it loses to (so a game has an odd number of shapes) */
+    pub fn new(names: &[&str], scores: &[i32], wins: &[(usize, usize)]) -> Result<Rules, String> {
+        let n = names.len();
+        if n == 0 {
+            return Err("at least one shape is needed".to_owned());
+        }
+        if scores.len() != n {
+            return Err(format!("{n} shape(s) were named, but {} were scored", scores.len()));
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        for &(winner, loser) in wins {
+            match (names.get(winner), names.get(loser)) {
+                (None, _) | (_, None) => return Err(format!("{winner} beats {loser}, but there are only {n} shapes")),
+                (Some(name), _) if winner == loser => return Err(format!("{name} beats itself")),
+                (Some(winner_name), Some(loser_name)) if beats[winner][loser] || beats[loser][winner] => {
+                    return Err(format!("the result of {winner_name} against {loser_name} is given twice"))
+                },
+                _ => beats[winner][loser] = true,
+            }
+        }
+
+        for a in 0..n {
+            for b in a + 1..n {
+                if !beats[a][b] && !beats[b][a] {
+                    return Err(format!("nothing says whether {} beats {}", names[a], names[b]));
+                }
+            }
+            let wins = beats[a].iter().filter(|&&wins| wins).count();
+            if 2 * wins != n - 1 {
+                return Err(format!("{} beats {wins} shape(s) and loses to {}", names[a], n - 1 - wins));
+            }
+        }
+
+        let names = names.iter().map(|&name| name.to_owned()).collect();
+        Ok(Rules { names, scores: scores.to_vec(), beats })
+    }
+
+    // Rock, Paper and Scissors, scoring 1, 2 and 3
+    pub fn rock_paper_scissors() -> Rules {
+        Rules::new(&["Rock", "Paper", "Scissors"], &[1, 2, 3], &[(0, 2), (1, 0), (2, 1)]).expect("The rules are fair")
+    }
+
+    // Rock, Paper, Scissors, Lizard and Spock, scoring 1 to 5
+    pub fn rock_paper_scissors_lizard_spock() -> Rules {
+        let wins = [(0, 2), (0, 3), (1, 0), (1, 4), (2, 1), (2, 3), (3, 1), (3, 4), (4, 0), (4, 2)];
+        Rules::new(&["Rock", "Paper", "Scissors", "Lizard", "Spock"], &[1, 2, 3, 4, 5], &wins).expect("The rules are fair")
+    }
+
+    /* Game of 'n' shapes (like RPS-7 or RPS-15) where each shape beats the (n - 1) / 2 shapes before
+     * it, wrapping around, and scores its position (from 1). With 3 shapes, this is Rock, Paper and
+     * Scissors */
+    pub fn cyclic(n: usize) -> Result<Rules, String> {
+        if n < 3 || n.is_multiple_of(2) {
+            return Err(format!("a cyclic game needs an odd number of shapes, at least 3, not {n}"));
+        }
+        let names: Vec<String> = (1..=n).map(|shape| format!("shape {shape}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let scores: Vec<i32> = (1..=n as i32).collect();
+        let wins: Vec<(usize, usize)> = (0..n).flat_map(|winner| (1..=n / 2).map(move |offset| (winner, (winner + n - offset) % n))).collect();
+        Rules::new(&names, &scores, &wins)
+    }
+
+    pub fn shapes(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, shape: usize) -> &str {
+        &self.names[shape]
+    }
+
+    // Outcome of a round for the player choosing 'yours'
+    pub fn outcome(&self, yours: usize, theirs: usize) -> Outcome {
+        if yours == theirs {
+            Outcome::Draw
+        }
+        else if self.beats[yours][theirs] {
+            Outcome::Win
+        }
+        else {
+            Outcome::Loss
+        }
+    }
+
+    /* Shape leading to the outcome against 'theirs'. When several do (with more than 3 shapes), the one
+     * scoring the most is chosen */
+    pub fn shape_for(&self, outcome: Outcome, theirs: usize) -> usize {
+        (0..self.shapes())
+            .filter(|&yours| self.outcome(yours, theirs) == outcome)
+            .max_by_key(|&yours| self.scores[yours])
+            .expect("Every outcome is possible in a fair game")
+    }
+
+    // Score of a round: the score of your shape, and 6 for a win, 3 for a draw or 0 for a loss
+    pub fn score(&self, yours: usize, outcome: Outcome) -> i32 {
+        self.scores[yours] + i32::from(outcome)
+    }
+}
+
+// Outcome needed with 'X', 'Y' or 'Z' in the second column (given as 0, 1 or 2)
+pub fn needed_outcome(column: usize) -> Outcome {
+    [Outcome::Loss, Outcome::Draw, Outcome::Win][column]
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    // Opponent's and your column for each round, as 0, 1 or 2 for 'A', 'B' or 'C' and 'X', 'Y' or 'Z'
+    type Input = Vec<(usize, usize)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(rounds: &str) -> Result<Vec<(usize, usize)>, AocError> {
+        rounds.lines().enumerate().map(|(line_idx, round)| {
+
+            // Getting opponent's/your decision, which must be 'A', 'B' or 'C' and 'X', 'Y' or 'Z'
+            match round.split_once(' ') {
+                Some((opponent_label @ ("A" | "B" | "C"), your_label @ ("X" | "Y" | "Z"))) => {
+                    Ok(((opponent_label.as_bytes()[0] - b'A').into(), (your_label.as_bytes()[0] - b'X').into()))
+                },
+                _ => Err(AocError::parse(Day2::DAY, line_idx + 1, round, "a round like 'A Y'")),
+            }
+        }).collect()
+    }
+
+    // The second column is the shape you play
+    fn part_one(rounds: &Vec<(usize, usize)>) -> i32 {
+        let rules = Rules::rock_paper_scissors();
+        rounds.iter().map(|&(theirs, yours)| rules.score(yours, rules.outcome(yours, theirs))).sum()
+    }
+
+    // The second column is the outcome you need
+    fn part_two(rounds: &Vec<(usize, usize)>) -> i32 {
+        let rules = Rules::rock_paper_scissors();
+        rounds
+            .iter()
+            .map(|&(theirs, column)| {
+                let outcome = needed_outcome(column);
+                rules.score(rules.shape_for(outcome, theirs), outcome)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_example() {
+        let example = examples::load(2022, 2, "example").unwrap();
+        let rounds = Day2::parse(&example.input).unwrap();
+
+        assert_eq!((Day2::part_one(&rounds), Day2::part_two(&rounds)), (15, 12));
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(Rules::cyclic(3).unwrap().beats, Rules::rock_paper_scissors().beats);
+
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        assert_eq!(rules.outcome(4, 2), Outcome::Win); // Spock smashes Scissors
+        assert_eq!(rules.outcome(3, 4), Outcome::Win); // Lizard poisons Spock
+        assert_eq!(rules.shape_for(Outcome::Loss, 0), 3); // Scissors and Lizard lose to Rock; Lizard scores more
+        assert_eq!(rules.shape_for(Outcome::Win, 0), 4);
+
+        let rps15 = Rules::cyclic(15).unwrap();
+        assert_eq!((0..15).filter(|&shape| rps15.outcome(7, shape) == Outcome::Win).count(), 7);
+        assert_eq!(rps15.score(14, Outcome::Draw), 18);
+    }
+
+    #[test]
+    fn test_inconsistent_rules() {
+        for n in [0, 1, 2, 4, 14] {
+            assert_eq!(Rules::cyclic(n), Err(format!("a cyclic game needs an odd number of shapes, at least 3, not {n}")));
+        }
+        assert_eq!(Rules::new(&["Rock", "Paper"], &[1], &[]), Err("2 shape(s) were named, but 1 were scored".to_owned()));
+        assert_eq!(Rules::new(&["Rock"], &[1], &[(0, 0)]), Err("Rock beats itself".to_owned()));
+        assert_eq!(Rules::new(&[], &[], &[]), Err("at least one shape is needed".to_owned()));
+
+        let names = ["Rock", "Paper", "Scissors"];
+        assert_eq!(Rules::new(&names, &[1, 2, 3], &[(0, 2), (2, 0)]), Err("the result of Scissors against Rock is given twice".to_owned()));
+        assert_eq!(Rules::new(&names, &[1, 2, 3], &[(0, 2), (1, 0)]), Err("nothing says whether Paper beats Scissors".to_owned()));
+        assert_eq!(Rules::new(&names, &[1, 2, 3], &[(0, 1), (0, 2), (1, 2)]), Err("Rock beats 2 shape(s) and loses to 0".to_owned()));
+    }
+}
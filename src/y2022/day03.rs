@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use crate::{AocError, Solution, Variant};
+
+const LOWERCASE_ASCII: i32 = 97;
+const UPPERCASE_ASCII: i32 = 65;
+
+const ALPHABET: i32 = 26;
+
+const LOWERCASE_PRIORITY: i32 = LOWERCASE_ASCII - 1;
+const UPPERCASE_PRIORITY: i32 = (UPPERCASE_ASCII - 1) - ALPHABET;
+
+// Finds the common element in both strings.
+// In this exercise, it's guaranteed that only one char is common for both strings
+pub fn find_intersection_element(str1: &str, str2: &str) -> char {
+    
+    let str1_set: HashSet<char> = str1.chars().collect();
+    let str2_set: HashSet<char> = str2.chars().collect();
+
+    *str1_set.intersection(&str2_set).next().expect("The strings don't have any character in common.")   
+}
+
+// Calculates the character priority depending on if it is uppercase or lowercase
+pub fn calculate_char_priority(char: char) -> i32 {
+    if char.is_lowercase() {
+        (char as i32) - LOWERCASE_PRIORITY
+    }
+    else if char.is_ascii_uppercase() {
+        (char as i32) - UPPERCASE_PRIORITY
+    } else {
+        0
+    }
+}
+
+// Finds the group's badge char and returns its priority
+pub fn find_group_badge(str1: &str, str2: &str, str3: &str) -> i32 {
+    let str1_set: HashSet<char> = str1.chars().collect();
+    let str2_set: HashSet<char> = str2.chars().collect();
+
+    let mut priority = 0;
+    for common_char in str1_set.intersection(&str2_set) {
+        if str3.contains(*common_char) {
+            priority = calculate_char_priority(*common_char);
+        }
+    }
+    priority
+}
+
+/* -- 'hashset' variant --
+ * Because inserting to a HashSet is an expensive operation, a Hashset is created from the shorter
+ * string (it doesn't matter in this exercise, both strings are the same size) and its elements are
+ * compared to an Iterator from the chars of the longer string */
+pub fn find_intersection_element_by_lookup(str1: &str, str2: &str) -> char {
+    
+    let (shorter_str, longer_str) = if str1.len() < str2.len() {
+        (str1, str2)
+    } else {
+        (str2, str1)
+    };
+
+    let chars_set: HashSet<char> = shorter_str.chars().collect();
+
+    longer_str.chars().find(|c| chars_set.contains(c)).expect("The strings don't have any character in common.")
+}
+
+// Same lookup for the badge: only the chars of the first rucksack go in a HashSet
+pub fn find_group_badge_by_lookup(str1: &str, str2: &str, str3: &str) -> i32 {
+    let chars_set: HashSet<char> = str1.chars().collect();
+    let common_set: HashSet<char> = str2.chars().filter(|c| chars_set.contains(c)).collect();
+
+    str3.chars().find(|c| common_set.contains(c)).map_or(0, calculate_char_priority)
+}
+
+/* -- 'bitmask' variant --
+ * Priorities go from 1 to 52, so the items of a string fit in the bits of a u64, where bit N is set
+ * if the string holds the item with priority N. Intersections are then a bitwise AND, and the
+ * priority of the common item is the position of the only bit left */
+pub fn priority_mask(str: &str) -> u64 {
+    str.chars().fold(0, |mask, char| mask | (1 << calculate_char_priority(char)))
+}
+
+fn mask_priority(mask: u64) -> i32 {
+    if mask == 0 { 0 } else { mask.trailing_zeros() as i32 }
+}
+
+fn part_one_lookup(items: &[String]) -> i32 {
+    items
+        .iter()
+        .map(|item| item.split_at(item.len() / 2))
+        .map(|(compartment1, compartment2)| calculate_char_priority(find_intersection_element_by_lookup(compartment1, compartment2)))
+        .sum()
+}
+
+fn part_two_lookup(items: &[String]) -> i32 {
+    items
+        .chunks(3)
+        .map(|group| find_group_badge_by_lookup(&group[0], &group[1], &group[2]))
+        .sum()
+}
+
+fn part_one_bitmask(items: &[String]) -> i32 {
+    items
+        .iter()
+        .map(|item| item.split_at(item.len() / 2))
+        .map(|(compartment1, compartment2)| mask_priority(priority_mask(compartment1) & priority_mask(compartment2)))
+        .sum()
+}
+
+fn part_two_bitmask(items: &[String]) -> i32 {
+    items
+        .chunks(3)
+        .map(|group| mask_priority(group.iter().map(|items| priority_mask(items)).fold(u64::MAX, |common, mask| common & mask)))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<String>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    const VARIANTS: &'static [Variant<Vec<String>, i32, i32>] = &[
+        Variant { name: "hashset", part_one: |items| part_one_lookup(items), part_two: |items| part_two_lookup(items) },
+        Variant { name: "bitmask", part_one: |items| part_one_bitmask(items), part_two: |items| part_two_bitmask(items) },
+    ];
+
+    fn parse(rucksack: &str) -> Result<Vec<String>, AocError> {
+        let items = rucksack.lines().enumerate().map(|(line_idx, item)| {
+
+            // Both compartments have the same number of items, identified by ASCII letters
+            if item.is_empty() || item.len() % 2 != 0 || !item.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(AocError::parse(Day3::DAY, line_idx + 1, item, "an even number of letters"));
+            }
+
+            // Every solver relies on an item being in both compartments
+            let (compartment1, compartment2) = item.split_at(item.len() / 2);
+            if priority_mask(compartment1) & priority_mask(compartment2) == 0 {
+                return Err(AocError::parse(Day3::DAY, line_idx + 1, item, "an item in both compartments"));
+            }
+            Ok(item.to_owned())
+        }).collect::<Result<Vec<String>, AocError>>()?;
+
+        if items.len() % 3 != 0 {
+            return Err(AocError::invalid(Day3::DAY, "the rucksacks can't be split in groups of three Elves"));
+        }
+
+        // Same for the badge, the item carried by the three Elves of a group
+        for (group_idx, group) in items.chunks(3).enumerate() {
+            if group.iter().fold(u64::MAX, |common, items| common & priority_mask(items)) == 0 {
+                return Err(AocError::parse(Day3::DAY, 3 * group_idx + 3, &group[2], "a rucksack sharing a badge with the two before it"));
+            }
+        }
+        Ok(items)
+    }
+
+    fn part_one(items: &Vec<String>) -> i32 {
+        let mut total_priority = 0;
+        for item in items {
+
+            // Splitting item in its two compartments
+            let (compartment1, compartment2) = item.split_at(item.len() / 2);
+
+            let common_char = find_intersection_element(compartment1, compartment2);
+
+            total_priority += calculate_char_priority(common_char);
+        }
+        total_priority
+    }
+
+    // Each group has three Elves, so the items are evaluated in chunks of three
+    fn part_two(items: &Vec<String>) -> i32 {
+        items
+            .chunks(3)
+            .map(|group| find_group_badge(&group[0], &group[1], &group[2]))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_rucksacks() {
+        let err = Day3::parse("abca\nabcd\naxyz\n").unwrap_err();
+        assert_eq!(err.to_string(), "day 3, line 2: expected an item in both compartments, found 'abcd'");
+
+        let err = Day3::parse("aa\nabca\nbb\naa\naa\naa\n").unwrap_err();
+        assert_eq!(err.to_string(), "day 3, line 3: expected a rucksack sharing a badge with the two before it, found 'bb'");
+        assert!(Day3::parse("aa\nabca\nbaab\n").is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{parsing, AocError, Solution};
+
+// Checks if a limit fully contain the other
+pub fn check_fully_overlapping_sections(limits_1: (i32, i32), limits_2: (i32, i32)) -> bool {
+    if limits_1.0 >= limits_2.0 && limits_1.1 <= limits_2.1 { // Limit 1 is contained in limit 2
+        true
+    }
+    else if limits_2.0 >= limits_1.0 && limits_2.1 <= limits_1.1 { // Limit 2 is contained in limit 1
+        true
+    }
+    else { // The limits don't fully overlap
+        false
+    }
+}
+
+// Checks if limits overlap at all
+pub fn check_overlapping_sections(limits_1: (i32, i32), limits_2: (i32, i32)) -> bool {
+    if check_fully_overlapping_sections(limits_1, limits_2) { // First, checking if there is a complete overlap
+        true
+    }
+    else {
+        // For each value of limit 1, check if it is contained in range of limit 2
+        for value in limits_1.0..(limits_1.1 + 1) {
+            if (limits_2.0..(limits_2.1 + 1)).contains(&value) {
+                return true;
+            }
+        }
+        false // The limits don't overlap at all
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<((i32, i32), (i32, i32))>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(assignment_pairs: &str) -> Result<Vec<((i32, i32), (i32, i32))>, AocError> {
+        parsing::lines(Day4::DAY, assignment_pairs).map(|pair| {
+
+            // Getting the interval of sections for each Elf of the pair of Elves, written as 'a-b'
+            let [start_1, end_1, start_2, end_2] = pair.extract("{}-{},{}-{}")?;
+            Ok(((pair.number(start_1)?, pair.number(end_1)?), (pair.number(start_2)?, pair.number(end_2)?)))
+        }).collect()
+    }
+
+    fn part_one(pairs: &Vec<((i32, i32), (i32, i32))>) -> usize {
+        pairs
+            .iter()
+            .filter(|(limits_1, limits_2)| check_fully_overlapping_sections(*limits_1, *limits_2))
+            .count()
+    }
+
+    fn part_two(pairs: &Vec<((i32, i32), (i32, i32))>) -> usize {
+        pairs
+            .iter()
+            .filter(|(limits_1, limits_2)| check_overlapping_sections(*limits_1, *limits_2))
+            .count()
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::VecDeque;
+
+use crate::{
+    grid::Grid,
+    parsing::{self, Paragraph},
+    visualize::{Cell, Colour, Frame, Frames, Visualize},
+    AocError, Part, Solution,
+};
+
+// Parsing stacks of crates from the paragraph of the drawing to VecDeques
+pub fn parse_crates(drawing: &Paragraph) -> Result<Vec<VecDeque<char>>, AocError> {
+    let mut number_of_stacks = 0;
+    let mut crates_per_layer = 0;
+
+    let mut stacks: Vec<VecDeque<char>> = Vec::new();
+
+    for layer in drawing.lines() {
+        
+        // Skipping the first element ( '[' ), and iterating by 4 to get each crate value
+        for stack_element in layer.text.chars().skip(1).step_by(4) {
+            
+            crates_per_layer += 1; // Incrementing number of elements per layer, be it a whitespace or a char
+
+            if crates_per_layer > number_of_stacks { // There are more crates than stacks, creating a new stack
+                stacks.push(VecDeque::new());
+                number_of_stacks += 1;
+            }
+            if stack_element.is_ascii_alphabetic() { // If the element is a char, push it to its correspondent stack
+                stacks[crates_per_layer - 1].push_front(stack_element);
+            }
+            else if stack_element != ' ' && !stack_element.is_ascii_digit() { // Only the last layer (stack numbers) has digits
+                return Err(layer.error("a layer of crates like '[A] [B]     [C]'"));
+            }
+        }
+        crates_per_layer = 0; // New layer, setting variable to 0 again
+    }
+    Ok(stacks)
+}
+
+// Moving crates between stacks 
+pub fn move_crates(stacks: &mut [VecDeque<char>], crates_to_move: i32, movement_position: (usize, usize), same_order: bool) {
+
+    let (from, to) = (movement_position.0, movement_position.1);
+
+    for crate_number in 0..crates_to_move {
+
+        if same_order { // The crates should stay in the same order when they move
+            let element = stacks[from - 1]
+                .pop_back()
+                .expect("Couldn't pop a crate from the stack");
+                // For each crate that moves, add it to index (len() - crate_number) to ensure the order doesn't change
+                stacks[to - 1].insert(stacks[to - 1].len() - crate_number as usize, element);
+        }
+        else { // Each crate moves individually and the order can change
+            let element = stacks[from - 1]
+                .pop_back()
+                .expect("Couldn't pop a crate from the stack");
+                stacks[to - 1].push_back(element);
+        }
+    }
+}
+
+// Finding the message that corresponds to which crates will end up on top of the stacks
+pub fn find_crates_at_top(stacks: &[VecDeque<char>]) -> String {
+    let mut top_crates: String = "".to_owned();
+
+    for stack in stacks {
+        if !stack.is_empty() {
+            let crate_label = stack.back().unwrap(); // Getting char from the back position
+            top_crates.push(*crate_label);
+        }
+    }
+    top_crates
+}
+
+// Moves 'crates_to_move' crates between stacks at positions (from, to)
+#[derive(Clone, Copy)]
+pub struct Instruction {
+    pub crates_to_move: i32,
+    pub position: (usize, usize),
+    pub line: usize, // Line of the instruction in the input, from 1
+}
+
+// Initial stacks of crates and the rearrangement procedure
+pub struct Drawing {
+    pub stacks: Vec<VecDeque<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+// Parsing instruction 'move N from A to B'
+pub fn parse_instruction(instruction: parsing::Line) -> Result<Instruction, AocError> {
+    let [crates_to_move, from, to] = instruction.extract("move {} from {} to {}")?;
+
+    Ok(Instruction {
+        crates_to_move: instruction.number(crates_to_move)?,
+        position: (instruction.number(from)?, instruction.number(to)?),
+        line: instruction.number,
+    })
+}
+
+/* Checks that every instruction moves crates between existing stacks, and never takes more crates
+ * than the stack holds at that point of the procedure */
+fn check_instructions(stacks: &[VecDeque<char>], instructions: &[Instruction]) -> Result<(), AocError> {
+    let mut stack_heights: Vec<i32> = stacks.iter().map(|stack| stack.len() as i32).collect();
+    let valid_stack = 1..=stacks.len();
+
+    for instruction in instructions {
+        let (from, to) = instruction.position;
+
+        let error = |reason: String| AocError::invalid(Day5::DAY, format!("instruction at line {} {reason}", instruction.line));
+        if !valid_stack.contains(&from) || !valid_stack.contains(&to) {
+            return Err(error(format!("refers to a stack outside 1-{}", stacks.len())));
+        }
+        if instruction.crates_to_move <= 0 {
+            return Err(error(format!("moves {} crates, but it should move at least one", instruction.crates_to_move)));
+        }
+        if stack_heights[from - 1] < instruction.crates_to_move {
+            return Err(error(format!("moves {} crates from a stack holding {}", instruction.crates_to_move, stack_heights[from - 1])));
+        }
+        stack_heights[from - 1] -= instruction.crates_to_move;
+        stack_heights[to - 1] += instruction.crates_to_move;
+    }
+    Ok(())
+}
+
+// Runs the whole procedure on a copy of the stacks and returns the message formed by the top crates
+fn rearrange(drawing: &Drawing, same_order: bool) -> String {
+    let mut stacks_of_crates: Vec<VecDeque<char>> = drawing.stacks.clone();
+
+    for instruction in &drawing.instructions {
+        move_crates(stacks_of_crates.as_mut_slice(), instruction.crates_to_move, instruction.position, same_order);
+    }
+    find_crates_at_top(&stacks_of_crates)
+}
+
+// Height of the tallest stack at any point of the procedure, whose instructions must have been checked
+fn tallest_stack(drawing: &Drawing) -> usize {
+    let mut stack_heights: Vec<usize> = drawing.stacks.iter().map(VecDeque::len).collect();
+    let mut tallest = stack_heights.iter().copied().max().unwrap_or(0);
+
+    for instruction in &drawing.instructions {
+        let (from, to) = instruction.position;
+        stack_heights[from - 1] -= instruction.crates_to_move as usize;
+        stack_heights[to - 1] += instruction.crates_to_move as usize;
+        tallest = tallest.max(stack_heights[to - 1]);
+    }
+    tallest
+}
+
+/* Draws the stacks like the drawing of the input, 'height' crates high. The crates that just moved
+ * (the 'moved' crates on top of a stack) are highlighted */
+fn draw_stacks(stacks: &[VecDeque<char>], height: usize, moved: Option<(usize, usize)>) -> Grid<Cell> {
+    let mut cells = Grid::new((4 * stacks.len()).saturating_sub(1), height + 1, Cell::plain(' '));
+
+    for (stack_idx, stack) in stacks.iter().enumerate() {
+        let x = 4 * stack_idx;
+        for (level, &label) in stack.iter().enumerate() {
+            let highlighted = moved.is_some_and(|(idx, crates)| idx == stack_idx && level + crates >= stack.len());
+            let cell = |symbol| if highlighted { Cell::coloured(symbol, Colour::Yellow) } else { Cell::plain(symbol) };
+
+            let y = height - 1 - level;
+            cells.set(x, y, cell('['));
+            cells.set(x + 1, y, cell(label));
+            cells.set(x + 2, y, cell(']'));
+        }
+        let number = char::from_digit((stack_idx as u32 + 1) % 10, 10).unwrap();
+        cells.set(x + 1, height, Cell::plain(number));
+    }
+    cells
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Drawing;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(drawing: &str) -> Result<Drawing, AocError> {
+        let [stacks, instructions] = parsing::paragraphs(Day5::DAY, drawing)[..] else {
+            return Err(AocError::invalid(Day5::DAY, "the drawing should be followed by an empty line and the instructions"));
+        };
+
+        let stacks = parse_crates(&stacks)?;
+
+        let instructions = instructions
+            .lines()
+            .map(parse_instruction)
+            .collect::<Result<Vec<Instruction>, AocError>>()?;
+
+        check_instructions(&stacks, &instructions)?;
+        Ok(Drawing { stacks, instructions })
+    }
+
+    // Moving crates without keeping the order
+    fn part_one(drawing: &Drawing) -> String {
+        rearrange(drawing, false)
+    }
+
+    // Moving crates without changing the order (CrateMover 9001)
+    fn part_two(drawing: &Drawing) -> String {
+        rearrange(drawing, true)
+    }
+}
+
+// One frame per instruction, with the crates it moved highlighted
+impl Visualize for Day5 {
+    fn frames(drawing: Drawing, part: Part) -> Result<Frames, AocError> {
+        let same_order = part == Part::Two;
+        check_instructions(&drawing.stacks, &drawing.instructions)?; // Drawings built without parsing aren't checked yet
+        let height = tallest_stack(&drawing);
+        let steps = drawing.instructions.len();
+        let Drawing { mut stacks, instructions } = drawing;
+
+        let start = Frame::new(draw_stacks(&stacks, height, None), format!("Top crates: {}", find_crates_at_top(&stacks)));
+        let moves = instructions.into_iter().enumerate().map(move |(idx, instruction)| {
+            let (from, to) = instruction.position;
+            move_crates(&mut stacks, instruction.crates_to_move, instruction.position, same_order);
+
+            let caption = format!(
+                "Step {}/{steps}: move {} from {from} to {to}, top crates: {}",
+                idx + 1,
+                instruction.crates_to_move,
+                find_crates_at_top(&stacks),
+            );
+            Frame::new(draw_stacks(&stacks, height, Some((to - 1, instruction.crates_to_move as usize))), caption)
+        });
+        Ok(Box::new(std::iter::once(start).chain(moves)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_invalid_move_counts() {
+        let example = examples::load(2022, 5, "example").unwrap();
+        for count in ["-1", "0"] {
+            let drawing = example.input.replacen("move 1 from 2 to 1", &format!("move {count} from 2 to 1"), 1);
+            let err = Day5::parse(&drawing).err().unwrap();
+            assert_eq!(err.to_string(), format!("day 5: instruction at line 6 moves {count} crates, but it should move at least one"));
+        }
+    }
+
+    // Drawings built without parsing are checked before being animated
+    #[test]
+    fn test_frames_of_invalid_instructions() {
+        let stacks = vec![VecDeque::from(['A']), VecDeque::new()];
+        let cases = [
+            (-1, (1, 2), "moves -1 crates, but it should move at least one"),
+            (2, (1, 2), "moves 2 crates from a stack holding 1"),
+            (1, (0, 1), "refers to a stack outside 1-2"),
+            (1, (1, 5), "refers to a stack outside 1-2"),
+        ];
+        for (crates_to_move, position, reason) in cases {
+            let drawing = Drawing { stacks: stacks.clone(), instructions: vec![Instruction { crates_to_move, position, line: 4 }] };
+            let err = Day5::frames(drawing, Part::One).err().unwrap();
+            assert_eq!(err.to_string(), format!("day 5: instruction at line 4 {reason}"));
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let example = examples::load(2022, 5, "example").unwrap();
+        let frames: Vec<Frame> = Day5::frames(Day5::parse(&example.input).unwrap(), Part::Two).unwrap().collect();
+
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0].caption, "Top crates: NDP");
+        assert_eq!(frames[4].caption, "Step 4/4: move 1 from 1 to 2, top crates: MCD");
+        assert_eq!(
+            frames[4].render(false),
+            concat!(
+                "        [D]\n",
+                "        [N]\n",
+                "        [Z]\n",
+                "[M] [C] [P]\n",
+                " 1   2   3 \n",
+                "\n",
+                "Step 4/4: move 1 from 1 to 2, top crates: MCD",
+            ),
+        );
+    }
+}
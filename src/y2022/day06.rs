@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use crate::{AocError, Solution};
+
+pub const PACKET_MARKER_SIZE: usize = 4;
+pub const MESSAGE_MARKER_SIZE: usize = 14;
+
+/* Finds the marker index ('initial marker index' + 'marker size').
+ * If the datastream has no marker, the index is -1 */
+pub fn find_marker_index(datastream: &str, marker_size: usize) -> i32 {
+    if datastream.is_empty() { // Datastream is empty, index -1 by default
+        -1
+    }
+    else {
+        let datastream = datastream.chars().collect::<Vec<char>>(); // Vec of chars from datastream
+        datastream
+            .windows(marker_size) // Creating an iterator over all contiguous windows of length 'marker_size' (the windows overlap!)
+            .enumerate() // Using enumerate() to register the starting index of each window
+            
+            /* Filtering by the first window whose HashSet is the same size of 'marker_size'.
+             * Therefore, all characters from that window are different and they constitute a valid marker */
+            .find(|(_, w)| HashSet::<&char>::from_iter(w.iter()).len() == marker_size)
+            .map_or(-1, |(marker_idx, _)| (marker_size + marker_idx) as i32)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input = String;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    // The datastream is a single line of lowercase letters
+    fn parse(datastream: &str) -> Result<String, AocError> {
+        let datastream = datastream.trim_end();
+
+        if datastream.is_empty() || !datastream.chars().all(|c| c.is_ascii_lowercase()) {
+            let first_line = datastream.lines().next().unwrap_or("");
+            return Err(AocError::parse(Day6::DAY, 1, first_line, "a single line of lowercase letters"));
+        }
+        Ok(datastream.to_owned())
+    }
+
+    fn part_one(datastream: &String) -> i32 {
+        find_marker_index(datastream, PACKET_MARKER_SIZE)
+    }
+
+    fn part_two(datastream: &String) -> i32 {
+        find_marker_index(datastream, MESSAGE_MARKER_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    
+    use super::*;
+
+    #[test]
+    fn test_marker_indexes() {
+        let input = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (datastream, packet_idx, message_idx) in input {
+            assert_eq!(find_marker_index(datastream, PACKET_MARKER_SIZE), packet_idx);
+            assert_eq!(find_marker_index(datastream, MESSAGE_MARKER_SIZE), message_idx);
+        }
+    }
+
+}
\ No newline at end of file
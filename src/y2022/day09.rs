@@ -0,0 +1,252 @@
+use crate::{
+    grid::{Grid, SparseGrid},
+    point::{Direction, Point},
+    visualize::{Cell, Colour, Frame, Frames, Visualize},
+    AocError, Part, Solution,
+};
+
+pub struct Head {
+    pub position: Point,
+}
+
+#[derive(Clone)] // Necessary to create a vec! using Tail::new()
+pub struct Tail {
+    pub position: Point,
+    pub visited_positions: SparseGrid<char>,
+}
+
+impl Default for Head {
+    fn default() -> Head {
+        Head::new()
+    }
+}
+
+impl Default for Tail {
+    fn default() -> Tail {
+        Tail::new()
+    }
+}
+
+impl Head {
+    pub fn new() -> Head { // Creates new instance of Head
+        Head {
+            position: Point::ORIGIN,
+        }
+    }
+
+    // Moves head one step in the direction
+    pub fn move_head(&mut self, direction: Direction) {
+        self.position += direction.offset();
+    }
+}
+
+impl Tail {
+
+    pub fn new() -> Tail { // Creates new instance of Tail
+        Tail {
+            position: Point::ORIGIN,
+            visited_positions: SparseGrid::new(), // Grid marking each position visited by the tail with '#'
+        }
+    }
+
+    /* Checking if moving the tail if necessary
+     * The tail only moves when it no longer touches the head (even diagonally), and then it takes
+     * one step towards the head on each axis where they differ */
+    pub fn check_if_tail_moves(&mut self, head_position: Point) {
+        if self.position.chebyshev(head_position) > 1 {
+            self.position += (head_position - self.position).signum();
+        }
+        self.visited_positions.set(self.position, '#'); // Marking the tail position; visiting it again changes nothing
+    }
+}
+
+// Rope made of a head and the knots following it
+pub struct Rope {
+    pub head: Head,
+    pub tails: Vec<Tail>,
+}
+
+impl Rope {
+    pub fn new(knots: usize) -> Rope {
+        Rope {
+            head: Head::new(),
+            tails: vec![Tail::new(); knots - 1], // Creating vector of Tails for all the knots after the head
+        }
+    }
+
+    // Moves the head one step, and every knot after it
+    pub fn step(&mut self, direction: Direction) {
+        self.head.move_head(direction);
+        self.tails[0].check_if_tail_moves(self.head.position);
+
+        // For all the other knots, they will move based on their previous knot
+        for tail_idx in 1..self.tails.len() {
+            let position = self.tails[tail_idx - 1].position; // Getting previous knot position
+            self.tails[tail_idx].check_if_tail_moves(position);
+        }
+    }
+
+    // Last knot of the rope
+    pub fn tail(&self) -> &Tail {
+        &self.tails[self.tails.len() - 1]
+    }
+}
+
+// Moves a rope of 'knots' knots according to the movements, and returns how many positions its tail visited
+pub fn simulate_rope(movements: &[(Direction, i32)], knots: usize) -> usize {
+    let mut rope = Rope::new(knots);
+
+    for &(direction, times_to_move) in movements {
+        for _ in 0..times_to_move {
+            rope.step(direction);
+        }
+    }
+    rope.tail().visited_positions.len()
+}
+
+// Largest part of the bridge shown in a frame; larger bridges are followed around the head
+const VIEW_WIDTH: i32 = 79;
+const VIEW_HEIGHT: i32 = 35;
+
+/* Draws the part of the bridge starting at 'corner': the knots (the head first, then the others by
+ * number, or 'T' for the tail of a two-knot rope), the start 's' and the positions visited by the tail */
+fn draw_rope(rope: &Rope, corner: Point, width: i32, height: i32) -> Grid<Cell> {
+    let mut cells = Grid::new(width as usize, height as usize, Cell::plain('.'));
+    let mut draw = |position: Point, cell: Cell| {
+        let relative = position - corner;
+        if (0..width).contains(&relative.x) && (0..height).contains(&relative.y) {
+            cells.set(relative.x as usize, relative.y as usize, cell);
+        }
+    };
+
+    for (position, _) in rope.tail().visited_positions.iter() {
+        draw(position, Cell::coloured('#', Colour::Blue));
+    }
+    draw(Point::ORIGIN, Cell::plain('s'));
+    for (idx, tail) in rope.tails.iter().enumerate().rev() { // Drawing the first knots last, on top of the others
+        let symbol = if rope.tails.len() == 1 { 'T' } else { char::from_digit(idx as u32 + 1, 36).unwrap() };
+        draw(tail.position, Cell::coloured(symbol, Colour::Yellow));
+    }
+    draw(rope.head.position, Cell::coloured('H', Colour::Red));
+    cells
+}
+
+pub struct Day9;
+
+// One frame per step of the head, with two knots for part one and ten for part two
+impl Visualize for Day9 {
+    fn frames(movements: Vec<(Direction, i32)>, part: Part) -> Result<Frames, AocError> {
+        let knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+
+        // Every knot stays within the area covered by the head
+        let mut head = Point::ORIGIN;
+        let (mut min, mut max) = (head, head);
+        for &(direction, times_to_move) in &movements {
+            head += direction.offset() * times_to_move;
+            min = Point::new(min.x.min(head.x), min.y.min(head.y));
+            max = Point::new(max.x.max(head.x), max.y.max(head.y));
+        }
+        let (width, height) = ((max.x - min.x + 1).min(VIEW_WIDTH), (max.y - min.y + 1).min(VIEW_HEIGHT));
+
+        let steps: i32 = movements.iter().map(|&(_, times_to_move)| times_to_move).sum();
+        let mut rope = Rope::new(knots);
+        let start = Frame::new(draw_rope(&rope, min, width, height), format!("{knots} knots at the start"));
+
+        let moves = movements
+            .into_iter()
+            .flat_map(|(direction, times_to_move)| std::iter::repeat_n(direction, times_to_move as usize))
+            .enumerate()
+            .map(move |(idx, direction)| {
+                rope.step(direction);
+
+                // Centering the view on the head, without going past the area
+                let head = rope.head.position;
+                let corner = Point::new(
+                    (head.x - width / 2).clamp(min.x, max.x + 1 - width),
+                    (head.y - height / 2).clamp(min.y, max.y + 1 - height),
+                );
+                let caption = format!(
+                    "Step {}/{steps}: {direction:?}, the tail visited {} positions",
+                    idx + 1,
+                    rope.tail().visited_positions.len(),
+                );
+                Frame::new(draw_rope(&rope, corner, width, height), caption)
+            });
+        Ok(Box::new(std::iter::once(start).chain(moves)))
+    }
+}
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<(Direction, i32)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(movements: &str) -> Result<Vec<(Direction, i32)>, AocError> {
+        movements.lines().enumerate().map(|(line_idx, movement)| {
+            match movement.split_once(' ').and_then(|(direction, value)| Some((direction.parse::<Direction>().ok()?, value))) {
+                Some((direction, value)) => {
+                    match value.parse::<i32>() {
+                        Ok(times_to_move) if times_to_move >= 0 => Ok((direction, times_to_move)),
+                        _ => Err(AocError::parse(Day9::DAY, line_idx + 1, movement, "a non-negative number of steps")),
+                    }
+                },
+                _ => Err(AocError::parse(Day9::DAY, line_idx + 1, movement, "a movement like 'R 4'")),
+            }
+        }).collect()
+    }
+
+    fn part_one(movements: &Vec<(Direction, i32)>) -> usize {
+        simulate_rope(movements, 2)
+    }
+
+    // On a larger rope, there are 10 knots
+    fn part_two(movements: &Vec<(Direction, i32)>) -> usize {
+        simulate_rope(movements, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_two_knots() {
+        let mut head = Head::new();
+        let mut tail = Tail::new();
+
+        let example = examples::load(2022, 9, "example1").unwrap();
+        for (direction, times_to_move) in Day9::parse(&example.input).unwrap() {
+            for _ in 0..times_to_move {
+                head.move_head(direction);
+                tail.check_if_tail_moves(head.position);
+            }
+        }
+        assert_eq!(tail.visited_positions.len(), 13);
+    }
+
+    #[test]
+    fn test_ten_knots() {
+        let example = examples::load(2022, 9, "example2").unwrap();
+        let movements = Day9::parse(&example.input).unwrap();
+
+        assert_eq!(simulate_rope(&movements, 10), 36);
+    }
+
+    #[test]
+    fn test_frames() {
+        let example = examples::load(2022, 9, "example1").unwrap();
+        let frames: Vec<Frame> = Day9::frames(Day9::parse(&example.input).unwrap(), Part::One).unwrap().collect();
+
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[24].caption, "Step 24/24: Right, the tail visited 13 positions");
+        assert_eq!(frames[24].render(false), "..##..\n...##.\n.TH##.\n....#.\ns###..\n\nStep 24/24: Right, the tail visited 13 positions");
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    grid::Grid,
+    visualize::{Cell, Colour, Frame, Frames, Visualize},
+    Answer, AocError, Part, Solution,
+};
+
+pub const DISPLAY_WIDTH: usize = 40;
+pub const DISPLAY_HEIGHT: usize = 6;
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+    Undefined,
+}
+
+pub struct Cpu {
+    pub x: i32, // Register
+    pub cycle: i32,
+    pub current_instruction: Instruction,
+    pub total_signal_strength: i32,
+    pub display: Grid<char>,
+
+    /* Counter that stores how many interesting cycles have been evaluated already
+     * Interesting cycles have the structure [20 + (current cycle * counter)] */
+    interesting_cycles_counter: i32,
+}
+
+impl Instruction {
+
+    // Parsing a single instruction, which is either 'noop' or 'addx [value]'
+    pub fn parse(line: usize, input: &str) -> Result<Instruction, AocError> {
+        if input == "noop" {
+            return Ok(Instruction::Noop);
+        }
+
+        input
+            .strip_prefix("addx ")
+            .and_then(|value| value.parse().ok())
+            .map(Instruction::Addx)
+            .ok_or_else(|| AocError::parse(Day10::DAY, line, input, "'noop' or 'addx [value]'"))
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Cpu {
+        Cpu::new()
+    }
+}
+
+impl Cpu {
+
+    // Creates a new CPU instance
+    pub fn new() -> Cpu {
+        Cpu {
+            x: 1,
+            cycle: 0,
+            current_instruction: Instruction::Undefined,
+            total_signal_strength: 0,
+            interesting_cycles_counter: 0,
+            display: Grid::new(DISPLAY_WIDTH, DISPLAY_HEIGHT, '.'),
+        }
+    }
+
+    // Parsing instruction from string to enum Instruction; 'line' is the position of the instruction in the program
+    pub fn parse_instruction(&mut self, line: usize, input: &str) -> Result<(), AocError> {
+        self.current_instruction = Instruction::parse(line, input)?;
+        Ok(())
+    }
+
+    /* Increments the cycle.
+     * Before that, the draw() method is called because the CRT draws a single pixel during each cycle */
+    fn increment_cycle(&mut self) {
+        self.draw_display();
+        self.cycle += 1;
+    }
+
+    // Runs one CPU cycle
+    pub fn tick(&mut self) {
+        match self.current_instruction {
+            Instruction::Addx(value) => { // Increments the cycle by 2 and adds a value to the register X
+                self.increment_cycle();
+                self.determine_signal_strength(); // Calculating the signal strength between each cycle increment
+                self.increment_cycle();
+                self.x += value;
+            },
+            Instruction::Noop => {
+                self.increment_cycle() // noop instruction only increments the cycle counter
+            },
+            Instruction::Undefined => (),
+        }
+        self.determine_signal_strength(); // Cycle has incremented; calculating signal strength
+    }
+
+    // Determines signal strength if the current cycle is an interesting one, and adds the signal to the total value
+    fn determine_signal_strength(&mut self) {
+        let interesting_cycles = 20 + 40 * self.interesting_cycles_counter;
+        if self.cycle + 1 == interesting_cycles {
+            self.total_signal_strength += self.x * (self.cycle + 1);
+            self.interesting_cycles_counter += 1; // Interesting cycle has been evaluated; incrementing counter
+        } // Otherwise, the cycle is not interesting; doesn't do anything
+    }
+
+    // Draws a single pixel based on the cycle number
+    fn draw_display(&mut self) {
+        
+        // Getting CRT range where the sprite is located
+        let sprite_idx_min = self.x - 1;
+        let sprite_idx_max = self.x + 1;
+
+        // Gets current position from 1D to 2D based on current cycle
+        let display_position = self.cycle % (DISPLAY_WIDTH as i32);
+        let display_row = self.cycle as usize / DISPLAY_WIDTH;
+
+        // Checks if position is in the sprite range
+        if (sprite_idx_min..sprite_idx_max + 1).contains(&display_position) {
+            self.display.set(display_position as usize, display_row, '#');
+        }
+    }
+
+    // Renders the display as text, one line per display row
+    pub fn render_display(&self) -> String {
+        self.display.to_string()
+    }
+
+    /* Draws the display with the pixel the beam draws next highlighted, and the sprite (register X)
+     * under it */
+    fn draw_state(&self) -> Grid<Cell> {
+        let mut cells = Grid::new(DISPLAY_WIDTH, DISPLAY_HEIGHT + 2, Cell::plain(' '));
+        for ((x, y), &pixel) in self.display.iter() {
+            let cell = if pixel == '#' { Cell::coloured(pixel, Colour::Green) } else { Cell::plain(pixel) };
+            cells.set(x, y, cell);
+        }
+
+        let beam = self.cycle as usize;
+        if beam < DISPLAY_WIDTH * DISPLAY_HEIGHT {
+            let (x, y) = (beam % DISPLAY_WIDTH, beam / DISPLAY_WIDTH);
+            let pixel = self.display.get(x, y).copied().unwrap_or('.');
+            cells.set(x, y, Cell::coloured(pixel, Colour::Yellow));
+        }
+        for x in self.x - 1..=self.x + 1 {
+            if (0..DISPLAY_WIDTH as i32).contains(&x) {
+                cells.set(x as usize, DISPLAY_HEIGHT + 1, Cell::coloured('=', Colour::Cyan));
+            }
+        }
+        cells
+    }
+}
+
+// Runs every instruction of the program on a new CPU
+pub fn run_program(instructions: &[Instruction]) -> Cpu {
+    let mut cpu: Cpu = Cpu::new();
+
+    for instruction in instructions {
+        cpu.current_instruction = *instruction;
+        cpu.tick();
+    }
+    cpu
+}
+
+pub struct Day10;
+
+// One frame per instruction, showing the image being drawn (both parts run the same program)
+impl Visualize for Day10 {
+    fn frames(instructions: Vec<Instruction>, _part: Part) -> Result<Frames, AocError> {
+        let mut cpu = Cpu::new();
+        let caption = |cpu: &Cpu| format!("Cycle {}: X = {}, signal strength {}", cpu.cycle, cpu.x, cpu.total_signal_strength);
+
+        let start = Frame::new(cpu.draw_state(), caption(&cpu));
+        let ticks = instructions.into_iter().map(move |instruction| {
+            cpu.current_instruction = instruction;
+            cpu.tick();
+            Frame::new(cpu.draw_state(), caption(&cpu))
+        });
+        Ok(Box::new(std::iter::once(start).chain(ticks)))
+    }
+}
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = Answer;
+
+    fn parse(instructions: &str) -> Result<Vec<Instruction>, AocError> {
+        instructions
+            .lines()
+            .enumerate()
+            .map(|(line_idx, instruction)| Instruction::parse(line_idx + 1, instruction))
+            .collect()
+    }
+
+    fn part_one(instructions: &Vec<Instruction>) -> i32 {
+        run_program(instructions).total_signal_strength
+    }
+
+    // The answer is the image drawn on the CRT
+    fn part_two(instructions: &Vec<Instruction>) -> Answer {
+        Answer::Bitmap(run_program(instructions).render_display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_signal_strength() {
+        let mut cpu: Cpu = Cpu::new();
+        
+        let example = examples::load(2022, 10, "example").unwrap();
+        for (line_idx, instruction) in example.input.lines().enumerate() {
+            cpu.parse_instruction(line_idx + 1, instruction).unwrap();
+            cpu.tick();
+        }
+        assert_eq!(cpu.total_signal_strength, 13140); 
+    }
+
+    #[test]
+    fn test_crt() {
+        let expected_crt = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+        let example = examples::load(2022, 10, "example").unwrap();
+        let cpu = run_program(&Day10::parse(&example.input).unwrap());
+
+        assert_eq!(cpu.render_display(), expected_crt);
+    }
+
+    #[test]
+    fn test_frames() {
+        let example = examples::load(2022, 10, "example").unwrap();
+        let instructions = Day10::parse(&example.input).unwrap();
+        let frames: Vec<Frame> = Day10::frames(instructions.clone(), Part::Two).unwrap().collect();
+
+        assert_eq!(frames.len(), instructions.len() + 1);
+        let first_instruction = frames[1].render(false);
+        let rows: Vec<&str> = first_instruction.lines().collect();
+        assert_eq!(rows[0], format!("##{}", ".".repeat(38))); // 'addx 15' drew two pixels
+        assert_eq!(rows[7], format!("{}==={}", " ".repeat(15), " ".repeat(22))); // The sprite covers the pixels 15 to 17
+        assert_eq!(rows[9], "Cycle 2: X = 16, signal strength 0");
+        assert!(frames.last().unwrap().caption.ends_with("signal strength 13140"));
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+
+use crate::{parsing, AocError, Solution};
+
+// Operation that updates the worry level of an item when a Monkey inspects it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+
+    // Parsing operations written as 'new = old [+ or *] [number or old]'
+    pub fn parse(input: &str) -> Option<Operation> {
+        let (operator, operand) = input.strip_prefix("new = old ")?.split_once(' ')?;
+
+        match (operator, operand) {
+            ("*", "old") => Some(Operation::Square),
+            ("+", "old") => Some(Operation::Multiply(2)),
+            ("*", value) => Some(Operation::Multiply(value.parse().ok()?)),
+            ("+", value) => Some(Operation::Add(value.parse().ok()?)),
+            _ => None,
+        }
+    }
+
+    pub fn apply(&self, old: u64) -> u64 {
+        match self {
+            Operation::Add(value) => old + value,
+            Operation::Multiply(value) => old * value,
+            Operation::Square => old * old,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub operation: Operation,
+    pub test: u64,
+    pub monkey_if_true: u64,
+    pub monkey_if_false: u64,
+    pub items_inspected: u64,
+}
+
+impl Monkey {
+    pub fn new() -> Monkey { // Creates a new instance of Monkey
+        Monkey {
+            items: VecDeque::new(),
+            operation: Operation::Add(0),
+            test: 1,
+            monkey_if_true: 0,
+            monkey_if_false: 0,
+            items_inspected: 0,
+        }
+    }
+}
+
+impl Default for Monkey {
+    fn default() -> Monkey {
+        Monkey::new()
+    }
+}
+
+// Attributes every Monkey must have; Monkeys may start without items
+const REQUIRED_ATTRIBUTES: [&str; 4] = ["Operation", "Test", "If true", "If false"];
+
+// Parsing each Monkey (a paragraph starting with its header 'Monkey N:') into a vector of Monkeys
+pub fn parse_monkey_data(input: &str) -> Result<Vec<Monkey>, AocError> {
+    
+    let mut monkeys = Vec::<Monkey>::new();
+
+    for paragraph in parsing::paragraphs(Day11::DAY, input) {
+        let mut lines = paragraph.lines().map(parsing::Line::trim); // Indentation doesn't matter, only the attribute names
+
+        let header = lines.next().expect("paragraphs aren't empty");
+        let expected_header = || header.error(format!("the header 'Monkey {}:'", monkeys.len()));
+        let [idx] = header.extract("Monkey {}:").map_err(|_| expected_header())?;
+        if header.number::<usize>(idx).ok() != Some(monkeys.len()) {
+            return Err(expected_header());
+        }
+
+        let mut monkey = Monkey::new();
+        let mut attributes = Vec::new(); // The ones seen so far, since the defaults of 'Monkey::new()' aren't real values
+        for line in lines {
+            let (attribute, value) = line.text.split_once(": ").ok_or_else(|| line.error("an attribute like 'Test: divisible by 23'"))?;
+            attributes.push(attribute);
+            match attribute {
+                "Starting items" => {
+                    monkey.items = value.split(", ").map(|item| line.number(item)).collect::<Result<_, _>>()?;
+                },
+                "Operation" => {
+                    monkey.operation = Operation::parse(value).ok_or_else(|| line.error("an operation like 'new = old * 19'"))?;
+                },
+                "Test" => {
+                    let [test] = line.extract("Test: divisible by {}")?;
+                    monkey.test = line.number(test)?;
+                    if monkey.test == 0 {
+                        return Err(line.error("a test dividing by a positive number"));
+                    }
+                },
+                "If true" => {
+                    let [target] = line.extract("If true: throw to monkey {}")?;
+                    monkey.monkey_if_true = line.number(target)?;
+                },
+                "If false" => {
+                    let [target] = line.extract("If false: throw to monkey {}")?;
+                    monkey.monkey_if_false = line.number(target)?;
+                },
+                _ => return Err(line.error("'Starting items', 'Operation', 'Test', 'If true' or 'If false'")),
+            }
+        }
+
+        if let Some(missing) = REQUIRED_ATTRIBUTES.iter().find(|attribute| !attributes.contains(attribute)) {
+            let reason = format!("Monkey {} (line {}) has no '{missing}' attribute", monkeys.len(), header.number);
+            return Err(AocError::invalid(Day11::DAY, reason));
+        }
+        monkeys.push(monkey);
+    }
+
+    check_monkeys(&monkeys)?;
+    Ok(monkeys)
+}
+
+// Monkey business needs at least two Monkeys, and items can only be thrown to other existing Monkeys
+fn check_monkeys(monkeys: &[Monkey]) -> Result<(), AocError> {
+    if monkeys.len() < 2 {
+        return Err(AocError::invalid(Day11::DAY, "at least two Monkeys are needed"));
+    }
+
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.monkey_if_true, monkey.monkey_if_false] {
+            if target as usize >= monkeys.len() || target as usize == idx {
+                return Err(AocError::invalid(Day11::DAY, format!("Monkey {idx} can't throw items to Monkey {target}")));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Running 'n' monkey rounds
+pub fn monkey_rounds(monkeys: &mut [Monkey], n: u64, relief: bool) -> u64 {
+    
+    /* To avoid having very large worry levels, we can use modulo arithmetic to lower them,
+     * using lowest column multiple (LCM) of all their divisors (test numbers).
+     *
+     * But, because each worry level is divisible by a different number (test numbers) and all of them
+     * are prime (they are coprime relative to one another), we can multiply all of them to get the LCM.
+     * 
+     * Question: doesn't our worry operations affect the modulo operation?
+     * 
+     * In our case, the worry operation only multiplies or adds to the worry level.
+     * And modulo congruence is preserved for any multiplication or addition operations.
+     * 
+     * However, division does not preserve modulo congruence. Therefore, if 'relief' is true it will
+     * affect our result.
+     * 
+     * Detailed explanation: https://aoc.just2good.co.uk/2022/11#part-2 */
+    let modulus: u64 = monkeys.iter().map(|m| m.test).product(); // Multiplying all test values
+
+    for _ in 0..n { // Runs the loop for 'n' rounds
+        for idx in 0..monkeys.len() { // Runs each round for each Monkey
+
+            while !monkeys[idx].items.is_empty() { // Inspect each item from vector of items of each Monkey
+                let item = monkeys[idx].items.pop_front().unwrap();
+                let mut worry_lvl = monkeys[idx].operation.apply(item);
+                
+                if relief { // Divides worry level by three if you are relieved
+                    worry_lvl /= 3;
+                }
+                
+                if worry_lvl.is_multiple_of(monkeys[idx].test) { // Test is true, throw to true_idx monkey
+                    let true_monkey_idx = monkeys[idx].monkey_if_true as usize;
+                    monkeys[true_monkey_idx].items.push_back(worry_lvl % modulus);
+                }
+                else { // Test is false, throw to false_idx monkey
+                    let false_monkey_idx = monkeys[idx].monkey_if_false as usize;
+                    monkeys[false_monkey_idx].items.push_back(worry_lvl % modulus);
+                }
+                monkeys[idx].items_inspected += 1; // Increments the number of items inspected for each Monkey
+            }
+        }
+    }
+    // Creates vector of number of items inspected, and sort it in descending order
+    let mut inspections = monkeys.iter().map(|m| m.items_inspected).collect::<Vec<u64>>();
+    inspections.sort_by(|a, b| b.cmp(a));
+
+    inspections[0] * inspections[1] // Returns product of the two most active Monkeys
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(monkey_data: &str) -> Result<Vec<Monkey>, AocError> {
+        parse_monkey_data(monkey_data)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> u64 {
+        monkey_rounds(&mut monkeys.clone(), 20, true)
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> u64 {
+        monkey_rounds(&mut monkeys.clone(), 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+
+    #[test]
+    fn test_rounds_with_relief() {
+        let example = examples::load(2022, 11, "example").unwrap();
+        let mut monkeys = parse_monkey_data(&example.input).unwrap();
+
+        assert_eq!(monkey_rounds(&mut monkeys, 20, true), 10605);
+    }
+
+    #[test]
+    fn test_rounds_without_relief() {
+        let example = examples::load(2022, 11, "example").unwrap();
+        let mut monkeys = parse_monkey_data(&example.input).unwrap();
+
+        assert_eq!(monkey_rounds(&mut monkeys, 10000, false), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_monkey_data("Monkey 0:\n  Operation: new = old ^ 2").unwrap_err();
+        assert_eq!(err.to_string(), "day 11, line 2: expected an operation like 'new = old * 19', found 'Operation: new = old ^ 2'");
+
+        let err = parse_monkey_data("Monkey 0:\n  Test: divisible by x").unwrap_err();
+        assert_eq!(err.to_string(), "day 11, line 2: expected a number at column 20, found 'Test: divisible by x'");
+
+        let err = parse_monkey_data("Monkey 1:\n  Test: divisible by 5").unwrap_err();
+        assert_eq!(err.to_string(), "day 11, line 1: expected the header 'Monkey 0:', found 'Monkey 1:'");
+
+        let monkey = "Monkey 0:\n  Operation: new = old + 1\n  Test: divisible by 5\n  If true: throw to monkey 1\n  If false: throw to monkey 1";
+        let err = parse_monkey_data(monkey).unwrap_err();
+        assert_eq!(err.to_string(), "day 11: at least two Monkeys are needed");
+    }
+
+    #[test]
+    fn test_missing_attributes() {
+        let example = examples::load(2022, 11, "example").unwrap();
+        for (attribute, line) in [("Operation", "  Operation: new = old * 19\n"), ("Test", "  Test: divisible by 23\n"), ("If false", "    If false: throw to monkey 3\n")] {
+            let err = parse_monkey_data(&example.input.replacen(line, "", 1)).unwrap_err();
+            assert_eq!(err.to_string(), format!("day 11: Monkey 0 (line 1) has no '{attribute}' attribute"));
+        }
+    }
+}
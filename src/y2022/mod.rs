@@ -0,0 +1,26 @@
+//! Solutions of Advent of Code 2022.
+
+use crate::registry::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+/// Every solved day of 2022, ordered by day number
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::of::<day01::Day1>(),
+    Puzzle::of::<day02::Day2>(),
+    Puzzle::of::<day03::Day3>(),
+    Puzzle::of::<day04::Day4>(),
+    Puzzle::animated::<day05::Day5>(),
+    Puzzle::of::<day06::Day6>(),
+    Puzzle::animated::<day09::Day9>(),
+    Puzzle::animated::<day10::Day10>(),
+    Puzzle::of::<day11::Day11>(),
+];